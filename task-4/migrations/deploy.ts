@@ -4,9 +4,33 @@
 
 const anchor = require("@coral-xyz/anchor");
 
+const CONFIG_SEED = "CONFIG_SEED";
+const REPORT_THRESHOLD = 3;
+const BPF_LOADER_UPGRADEABLE_PROGRAM_ID = new anchor.web3.PublicKey("BPFLoaderUpgradeab1e11111111111111111111111");
+
 module.exports = async function (provider) {
   // Configure client to use the provider.
   anchor.setProvider(provider);
 
-  // Add your deploy script here.
+  // Tweets and comments need the program config, so it is created as part of
+  // the deployment. Only the upgrade authority (the deploying wallet) may do so.
+  const program = anchor.workspace.Twitter;
+  const [config] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from(CONFIG_SEED)],
+    program.programId
+  );
+  const [programData] = anchor.web3.PublicKey.findProgramAddressSync(
+    [program.programId.toBuffer()],
+    BPF_LOADER_UPGRADEABLE_PROGRAM_ID
+  );
+
+  await program.methods.initializeConfig(new anchor.BN(REPORT_THRESHOLD)).accounts(
+    {
+      authority: provider.wallet.publicKey,
+      config: config,
+      program: program.programId,
+      programData: programData,
+      systemProgram: anchor.web3.SystemProgram.programId
+    }
+  ).rpc();
 };
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::events::TweetMention;
+use crate::states::*;
+
+/// Returns the normalised (lowercase, deduplicated) `#tags` found in `content`.
+pub fn extract_hashtags(content: &str) -> Result<Vec<String>> {
+    let mut hashtags: Vec<String> = Vec::new();
+    for tag in tokens_after(content, '#') {
+        if tag.len() > HASHTAG_LENGTH {
+            return err!(TwitterError::HashtagTooLong);
+        }
+        if !hashtags.contains(&tag) {
+            hashtags.push(tag);
+        }
+    }
+    if hashtags.len() > MAX_HASHTAGS {
+        return err!(TwitterError::TooManyHashtags);
+    }
+    Ok(hashtags)
+}
+
+/// Returns the normalised (lowercase, deduplicated) `@handles` found in
+/// `content`. Tokens too long to be a handle are not treated as mentions.
+pub fn extract_mentions(content: &str) -> Result<Vec<String>> {
+    let mut mentions: Vec<String> = Vec::new();
+    for handle in tokens_after(content, '@') {
+        if handle.len() <= HANDLE_LENGTH && !mentions.contains(&handle) {
+            mentions.push(handle);
+        }
+    }
+    if mentions.len() > MAX_MENTIONS {
+        return err!(TwitterError::TooManyMentions);
+    }
+    Ok(mentions)
+}
+
+/// Emits a `TweetMention` for every `Handle` account passed in
+/// `remaining_accounts`. Each account must be the registry entry of one of
+/// the `mentions` parsed from the tweet, and may only be passed once.
+pub fn emit_mentions(
+    tweet: Pubkey,
+    tweet_author: Pubkey,
+    mentions: &[String],
+    remaining_accounts: &[AccountInfo],
+) -> Result<()> {
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    for (index, account) in remaining_accounts.iter().enumerate() {
+        require!(
+            !remaining_accounts[..index]
+                .iter()
+                .any(|previous| previous.key == account.key),
+            TwitterError::DuplicateMention
+        );
+        require_keys_eq!(*account.owner, crate::ID, TwitterError::InvalidMention);
+        let handle_account = Handle::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+
+        let handle = mentions
+            .iter()
+            .find(|handle| {
+                Pubkey::create_program_address(
+                    &[
+                        HANDLE_SEED.as_bytes(),
+                        handle.as_bytes(),
+                        &[handle_account.bump],
+                    ],
+                    &crate::ID,
+                )
+                .is_ok_and(|address| address == account.key())
+            })
+            .ok_or(TwitterError::InvalidMention)?;
+
+        emit!(TweetMention {
+            tweet,
+            tweet_author,
+            mentioned: handle_account.authority,
+            handle: handle.clone(),
+            timestamp,
+        });
+    }
+
+    Ok(())
+}
+
+/// Yields the lowercase word following each `marker` that starts a token.
+fn tokens_after(content: &str, marker: char) -> impl Iterator<Item = String> + '_ {
+    let mut previous: Option<char> = None;
+    content.char_indices().filter_map(move |(index, c)| {
+        let starts_token = c == marker && !previous.is_some_and(is_token_char);
+        previous = Some(c);
+        if !starts_token {
+            return None;
+        }
+        let token: String = content[index + c.len_utf8()..]
+            .chars()
+            .take_while(|c| is_token_char(*c))
+            .map(|c| c.to_ascii_lowercase())
+            .collect();
+        (!token.is_empty()).then_some(token)
+    })
+}
+
+fn is_token_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
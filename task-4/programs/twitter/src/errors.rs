@@ -6,14 +6,150 @@ pub enum TwitterError {
     TopicTooLong,
     #[msg("Cannot initialize, content too long")]
     ContentTooLong,
+    /// Deprecated: no longer returned, see `MaxReactionsReached`.
     #[msg("Maximum number of Likes Reached")]
     MaxLikesReached,
+    /// Deprecated: no longer returned, see `MaxReactionsReached`.
     #[msg("Maximum number of Dislikes Reached")]
     MaxDislikesReached,
+    /// Deprecated: no longer returned, see `MinReactionsReached`.
     #[msg("Minimum number of Likes Reached")]
     MinLikesReached,
+    /// Deprecated: no longer returned, see `MinReactionsReached`.
     #[msg("Minimum number of Dislikes Reached")]
     MinDislikesReached,
     #[msg("Comment too Long")]
     CommentTooLong,
+    #[msg("Maximum number of Edits Reached")]
+    MaxEditsReached,
+    #[msg("Parent tweet still exists")]
+    ParentTweetStillExists,
+    #[msg("Maximum number of Comments Reached")]
+    MaxCommentsReached,
+    #[msg("Minimum number of Comments Reached")]
+    MinCommentsReached,
+    #[msg("Reaction is already of this type")]
+    ReactionUnchanged,
+    #[msg("Handle is already taken")]
+    HandleTaken,
+    #[msg("Handle must be 1-15 lowercase letters, digits or underscores")]
+    InvalidHandle,
+    #[msg("Display name too Long")]
+    DisplayNameTooLong,
+    #[msg("Bio too Long")]
+    BioTooLong,
+    #[msg("Avatar URI too Long")]
+    AvatarUriTooLong,
+    #[msg("Cannot follow yourself")]
+    CannotFollowSelf,
+    #[msg("Already following this user")]
+    AlreadyFollowing,
+    #[msg("Maximum number of Follows Reached")]
+    MaxFollowsReached,
+    #[msg("Minimum number of Follows Reached")]
+    MinFollowsReached,
+    #[msg("Parent comment belongs to a different tweet")]
+    CommentParentMismatch,
+    #[msg("Parent comment account is required for replies")]
+    MissingParentComment,
+    #[msg("Maximum reply depth Reached")]
+    ReplyDepthExceeded,
+    #[msg("Maximum number of Retweets Reached")]
+    MaxRetweetsReached,
+    #[msg("Minimum number of Retweets Reached")]
+    MinRetweetsReached,
+    #[msg("Maximum number of Tweets Reached")]
+    MaxTweetsReached,
+    #[msg("Minimum number of Tweets Reached")]
+    MinTweetsReached,
+    #[msg("Too many hashtags in content")]
+    TooManyHashtags,
+    #[msg("Hashtag too Long")]
+    HashtagTooLong,
+    #[msg("Too many mentions in content")]
+    TooManyMentions,
+    #[msg("Mentioned account is not a handle referenced in the content")]
+    InvalidMention,
+    #[msg("Tip amount must be greater than zero")]
+    ZeroTipAmount,
+    #[msg("Cannot tip your own tweet")]
+    CannotTipSelf,
+    #[msg("Maximum amount of Tips Reached")]
+    MaxTipsReached,
+    #[msg("Maximum number of Bookmarks Reached")]
+    MaxBookmarksReached,
+    #[msg("Minimum number of Bookmarks Reached")]
+    MinBookmarksReached,
+    #[msg("A poll needs between 2 and 4 options")]
+    InvalidPollOptionCount,
+    #[msg("Poll option too Long")]
+    PollOptionTooLong,
+    #[msg("Poll end timestamp must be in the future")]
+    PollEndInPast,
+    #[msg("Poll has already ended")]
+    PollClosed,
+    #[msg("Poll option does not exist")]
+    InvalidPollOption,
+    #[msg("Maximum number of Votes Reached")]
+    MaxVotesReached,
+    #[msg("Only the program upgrade authority can initialize the config")]
+    NotUpgradeAuthority,
+    #[msg("Signer is not a moderator")]
+    NotModerator,
+    #[msg("Maximum number of Moderators Reached")]
+    MaxModeratorsReached,
+    #[msg("Account is already a moderator")]
+    AlreadyModerator,
+    #[msg("Account is not in the moderator set")]
+    ModeratorNotFound,
+    #[msg("Report threshold must be greater than zero")]
+    InvalidReportThreshold,
+    #[msg("Cannot report your own tweet")]
+    CannotReportSelf,
+    #[msg("Maximum number of Reports Reached")]
+    MaxReportsReached,
+    #[msg("Cannot block yourself")]
+    CannotBlockSelf,
+    #[msg("Tweet author has blocked this user")]
+    Blocked,
+    #[msg("Too many actions in the current window, try again later")]
+    RateLimited,
+    #[msg("Topic cannot be empty")]
+    TopicEmpty,
+    #[msg("Topic contains control characters")]
+    TopicInvalidCharacters,
+    #[msg("Content cannot be empty")]
+    ContentEmpty,
+    #[msg("Content contains control characters")]
+    ContentInvalidCharacters,
+    #[msg("Comment cannot be empty")]
+    CommentEmpty,
+    #[msg("Comment contains control characters")]
+    CommentInvalidCharacters,
+    #[msg("Maximum number of Reactions Reached")]
+    MaxReactionsReached,
+    #[msg("Minimum number of Reactions Reached")]
+    MinReactionsReached,
+    #[msg("Poll option cannot be empty")]
+    PollOptionEmpty,
+    #[msg("Poll option contains control characters")]
+    PollOptionInvalidCharacters,
+    #[msg("Display name contains control characters")]
+    DisplayNameInvalidCharacters,
+    #[msg("Bio contains control characters")]
+    BioInvalidCharacters,
+    #[msg("Avatar URI contains control characters")]
+    AvatarUriInvalidCharacters,
+    #[msg("Rate limit must allow at least one action per window")]
+    InvalidRateLimit,
+    #[msg("Profile already has a handle, release it first")]
+    HandleAlreadySet,
+    #[msg("Poll is still open")]
+    PollStillOpen,
+    #[msg("Only hidden tweets and comments can be unhidden")]
+    NotHidden,
+    #[msg("Tweet is not under review")]
+    NotUnderReview,
+    #[msg("The same handle account was passed more than once")]
+    DuplicateMention,
 }
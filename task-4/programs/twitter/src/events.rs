@@ -0,0 +1,307 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{ModerationReason, ReactionType};
+
+#[event]
+pub struct TweetCreated {
+    pub tweet: Pubkey,
+    pub tweet_author: Pubkey,
+    pub topic: String,
+    pub quoted_tweet: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TweetMigrated {
+    pub legacy_tweet: Pubkey,
+    pub tweet: Pubkey,
+    pub tweet_author: Pubkey,
+    pub tweet_id: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TweetMention {
+    pub tweet: Pubkey,
+    pub tweet_author: Pubkey,
+    pub mentioned: Pubkey,
+    pub handle: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TweetEdited {
+    pub tweet: Pubkey,
+    pub tweet_author: Pubkey,
+    pub previous_content_hash: [u8; 32],
+    pub edit_count: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TweetDeleted {
+    pub tweet: Pubkey,
+    pub tweet_author: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TweetTipped {
+    pub tweet: Pubkey,
+    pub tipper: Pubkey,
+    pub tweet_author: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TweetTokenTipped {
+    pub tweet: Pubkey,
+    pub tipper: Pubkey,
+    pub tweet_author: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TweetBookmarked {
+    pub bookmark: Pubkey,
+    pub user: Pubkey,
+    pub tweet: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BookmarkRemoved {
+    pub bookmark: Pubkey,
+    pub user: Pubkey,
+    pub tweet: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PollCreated {
+    pub poll: Pubkey,
+    pub tweet: Pubkey,
+    pub tweet_author: Pubkey,
+    pub end_timestamp: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PollVoted {
+    pub poll: Pubkey,
+    pub voter: Pubkey,
+    pub option_index: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReactionAdded {
+    pub reaction: Pubkey,
+    pub reaction_author: Pubkey,
+    pub tweet: Pubkey,
+    pub reaction_type: ReactionType,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReactionChanged {
+    pub reaction: Pubkey,
+    pub reaction_author: Pubkey,
+    pub tweet: Pubkey,
+    pub previous_reaction_type: ReactionType,
+    pub reaction_type: ReactionType,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReactionRemoved {
+    pub reaction: Pubkey,
+    pub reaction_author: Pubkey,
+    pub tweet: Pubkey,
+    pub reaction_type: ReactionType,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CommentAdded {
+    pub comment: Pubkey,
+    pub comment_author: Pubkey,
+    pub tweet: Pubkey,
+    pub comment_id: u64,
+    pub parent_comment: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CommentRemoved {
+    pub comment: Pubkey,
+    pub comment_author: Pubkey,
+    pub tweet: Pubkey,
+    pub comment_id: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct Retweeted {
+    pub retweet: Pubkey,
+    pub retweeter: Pubkey,
+    pub original_tweet: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RetweetUndone {
+    pub retweet: Pubkey,
+    pub retweeter: Pubkey,
+    pub original_tweet: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProfileCreated {
+    pub user_profile: Pubkey,
+    pub authority: Pubkey,
+    pub handle: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProfileUpdated {
+    pub user_profile: Pubkey,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct HandleReleased {
+    pub user_profile: Pubkey,
+    pub authority: Pubkey,
+    pub handle: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct HandleClaimed {
+    pub user_profile: Pubkey,
+    pub authority: Pubkey,
+    pub handle: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct Followed {
+    pub follower: Pubkey,
+    pub followee: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct Unfollowed {
+    pub follower: Pubkey,
+    pub followee: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UserBlocked {
+    pub blocker: Pubkey,
+    pub blocked: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UserUnblocked {
+    pub blocker: Pubkey,
+    pub blocked: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigInitialized {
+    pub admin: Pubkey,
+    pub report_threshold: u64,
+    pub timestamp: i64,
+}
+
+/// Carries every setting after the change, whichever one was updated.
+#[event]
+pub struct ConfigUpdated {
+    pub admin: Pubkey,
+    pub report_threshold: u64,
+    pub rate_limit_window_slots: u64,
+    pub rate_limit_max_actions: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ModeratorAdded {
+    pub admin: Pubkey,
+    pub moderator: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ModeratorRemoved {
+    pub admin: Pubkey,
+    pub moderator: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TweetReported {
+    pub report: Pubkey,
+    pub tweet: Pubkey,
+    pub reporter: Pubkey,
+    pub reason: ModerationReason,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TweetUnderReview {
+    pub tweet: Pubkey,
+    pub report_count: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TweetHidden {
+    pub tweet: Pubkey,
+    pub moderator: Pubkey,
+    pub reason: ModerationReason,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReviewDismissed {
+    pub tweet: Pubkey,
+    pub moderator: Pubkey,
+    pub report_count: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TweetUnhidden {
+    pub tweet: Pubkey,
+    pub moderator: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CommentHidden {
+    pub comment: Pubkey,
+    pub tweet: Pubkey,
+    pub moderator: Pubkey,
+    pub reason: ModerationReason,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CommentUnhidden {
+    pub comment: Pubkey,
+    pub tweet: Pubkey,
+    pub moderator: Pubkey,
+    pub timestamp: i64,
+}
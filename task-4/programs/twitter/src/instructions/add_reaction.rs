@@ -1,32 +1,31 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::TwitterError;
+use crate::events::ReactionAdded;
 use crate::states::*;
 
 pub fn add_reaction(ctx: Context<AddReactionContext>, reaction: ReactionType) -> Result<()> {
+    let clock = Clock::get()?;
     let tweet = &mut ctx.accounts.tweet;
     let tweet_reaction = &mut ctx.accounts.tweet_reaction;
 
-    match reaction {
-        ReactionType::Like => {
-            if tweet.likes == u64::MAX {
-                return err!(TwitterError::MaxLikesReached);
-            }
-            tweet.likes += 1;
-        }
-        ReactionType::Dislike => {
-            if tweet.dislikes == u64::MAX {
-                return err!(TwitterError::MaxDislikesReached);
-            }
-            tweet.dislikes += 1;
-        }
-    }
+    tweet.increment_reaction(reaction)?;
 
     tweet_reaction.reaction_author = *ctx.accounts.reaction_author.key;
     tweet_reaction.parent_tweet = *ctx.accounts.tweet.to_account_info().key;
     tweet_reaction.reaction = reaction;
+    tweet_reaction.created_at = clock.unix_timestamp;
+    tweet_reaction.created_slot = clock.slot;
     tweet_reaction.bump = ctx.bumps.tweet_reaction;
 
+    emit!(ReactionAdded {
+        reaction: tweet_reaction.key(),
+        reaction_author: tweet_reaction.reaction_author,
+        tweet: tweet_reaction.parent_tweet,
+        reaction_type: reaction,
+        timestamp: clock.unix_timestamp,
+    });
+
     Ok(())
 }
 
@@ -38,6 +37,18 @@ pub struct AddReactionContext<'info> {
     #[account(mut)]
     pub tweet: Account<'info, Tweet>,
 
+    /// CHECK: block PDA of the tweet author against the reaction author, which must not exist.
+    #[account(
+        seeds = [
+            BLOCK_SEED.as_bytes(),
+            tweet.tweet_author.as_ref(),
+            reaction_author.key().as_ref()
+        ],
+        bump,
+        constraint = author_block.data_is_empty() @ TwitterError::Blocked
+    )]
+    pub author_block: UncheckedAccount<'info>,
+
     #[account(
         init,
         payer = reaction_author,
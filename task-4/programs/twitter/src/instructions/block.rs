@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::events::{UserBlocked, UserUnblocked};
+use crate::states::*;
+
+pub fn block_user(ctx: Context<BlockUserContext>) -> Result<()> {
+    let block = &mut ctx.accounts.block;
+    block.blocker = *ctx.accounts.blocker.key;
+    block.blocked = *ctx.accounts.blocked.key;
+    block.bump = ctx.bumps.block;
+
+    emit!(UserBlocked {
+        blocker: block.blocker,
+        blocked: block.blocked,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn unblock_user(ctx: Context<UnblockUserContext>) -> Result<()> {
+    emit!(UserUnblocked {
+        blocker: ctx.accounts.block.blocker,
+        blocked: ctx.accounts.block.blocked,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct BlockUserContext<'info> {
+    #[account(mut)]
+    pub blocker: Signer<'info>,
+
+    /// CHECK: any account can be blocked, its key is only used as a seed.
+    #[account(
+        constraint = blocked.key() != blocker.key() @ TwitterError::CannotBlockSelf
+    )]
+    pub blocked: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = blocker,
+        space = Block::INIT_SPACE + 8,
+        seeds = [
+            BLOCK_SEED.as_bytes(),
+            blocker.key().as_ref(),
+            blocked.key().as_ref()
+        ],
+        bump
+    )]
+    pub block: Account<'info, Block>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UnblockUserContext<'info> {
+    #[account(mut)]
+    pub blocker: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = blocker,
+        close = blocker,
+        seeds = [
+            BLOCK_SEED.as_bytes(),
+            blocker.key().as_ref(),
+            block.blocked.as_ref()
+        ],
+        bump = block.bump
+    )]
+    pub block: Account<'info, Block>,
+}
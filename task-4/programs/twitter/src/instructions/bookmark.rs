@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::events::{BookmarkRemoved, TweetBookmarked};
+use crate::states::*;
+
+pub fn bookmark_tweet(ctx: Context<BookmarkTweetContext>) -> Result<()> {
+    let bookmark = &mut ctx.accounts.bookmark;
+    bookmark.user = *ctx.accounts.user.key;
+    bookmark.tweet = ctx.accounts.tweet.key();
+    bookmark.bump = ctx.bumps.bookmark;
+
+    let author_state = &mut ctx.accounts.author_state;
+    author_state.initialize_if_new(*ctx.accounts.user.key, ctx.bumps.author_state);
+    author_state.bookmark_count = author_state
+        .bookmark_count
+        .checked_add(1)
+        .ok_or(TwitterError::MaxBookmarksReached)?;
+
+    emit!(TweetBookmarked {
+        bookmark: bookmark.key(),
+        user: bookmark.user,
+        tweet: bookmark.tweet,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn remove_bookmark(ctx: Context<RemoveBookmarkContext>) -> Result<()> {
+    let author_state = &mut ctx.accounts.author_state;
+    author_state.bookmark_count = author_state
+        .bookmark_count
+        .checked_sub(1)
+        .ok_or(TwitterError::MinBookmarksReached)?;
+
+    let bookmark = &ctx.accounts.bookmark;
+    emit!(BookmarkRemoved {
+        bookmark: bookmark.key(),
+        user: bookmark.user,
+        tweet: bookmark.tweet,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct BookmarkTweetContext<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub tweet: Account<'info, Tweet>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = AuthorState::INIT_SPACE + 8,
+        seeds = [
+            AUTHOR_STATE_SEED.as_bytes(),
+            user.key().as_ref()
+        ],
+        bump
+    )]
+    pub author_state: Account<'info, AuthorState>,
+
+    #[account(
+        init,
+        payer = user,
+        space = Bookmark::INIT_SPACE + 8,
+        seeds = [
+            BOOKMARK_SEED.as_bytes(),
+            user.key().as_ref(),
+            tweet.key().as_ref()
+        ],
+        bump
+    )]
+    pub bookmark: Account<'info, Bookmark>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// The tweet itself is not required, so bookmarks of deleted tweets can still
+/// be removed.
+#[derive(Accounts)]
+pub struct RemoveBookmarkContext<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            AUTHOR_STATE_SEED.as_bytes(),
+            user.key().as_ref()
+        ],
+        bump = author_state.bump
+    )]
+    pub author_state: Account<'info, AuthorState>,
+
+    #[account(
+        mut,
+        has_one = user,
+        close = user,
+        seeds = [
+            BOOKMARK_SEED.as_bytes(),
+            user.key().as_ref(),
+            bookmark.tweet.as_ref()
+        ],
+        bump = bookmark.bump
+    )]
+    pub bookmark: Account<'info, Bookmark>,
+}
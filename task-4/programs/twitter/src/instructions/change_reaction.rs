@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::events::ReactionChanged;
+use crate::states::*;
+
+pub fn change_reaction(ctx: Context<ChangeReactionContext>, reaction: ReactionType) -> Result<()> {
+    let tweet = &mut ctx.accounts.tweet;
+    let tweet_reaction = &mut ctx.accounts.tweet_reaction;
+
+    if tweet_reaction.reaction == reaction {
+        return err!(TwitterError::ReactionUnchanged);
+    }
+
+    // 1. Undo the previous reaction on the tweet counters
+    tweet.decrement_reaction(tweet_reaction.reaction)?;
+
+    // 2. Apply the new reaction
+    tweet.increment_reaction(reaction)?;
+
+    let previous_reaction_type = tweet_reaction.reaction;
+    tweet_reaction.reaction = reaction;
+
+    emit!(ReactionChanged {
+        reaction: tweet_reaction.key(),
+        reaction_author: tweet_reaction.reaction_author,
+        tweet: tweet.key(),
+        previous_reaction_type,
+        reaction_type: reaction,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ChangeReactionContext<'info> {
+    pub reaction_author: Signer<'info>,
+
+    #[account(mut)]
+    pub tweet: Account<'info, Tweet>,
+
+    #[account(
+        mut,
+        has_one = reaction_author,
+        seeds = [
+            TWEET_REACTION_SEED.as_bytes(),
+            reaction_author.key().as_ref(),
+            tweet.key().as_ref()
+        ],
+        bump = tweet_reaction.bump
+    )]
+    pub tweet_reaction: Account<'info, Reaction>,
+}
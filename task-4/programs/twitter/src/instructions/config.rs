@@ -0,0 +1,144 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::events::{ConfigInitialized, ConfigUpdated, ModeratorAdded, ModeratorRemoved};
+use crate::program::Twitter;
+use crate::states::*;
+
+/// Tweets and comments read the rate limit from the config, so this has to
+/// run right after the program is deployed, before anyone can post.
+pub fn initialize_config(ctx: Context<InitializeConfigContext>, report_threshold: u64) -> Result<()> {
+    if report_threshold == 0 {
+        return err!(TwitterError::InvalidReportThreshold);
+    }
+
+    let config = &mut ctx.accounts.config;
+    config.admin = *ctx.accounts.authority.key;
+    config.moderators = Vec::new();
+    config.report_threshold = report_threshold;
+    config.rate_limit_window_slots = 0;
+    config.rate_limit_max_actions = 0;
+    config.bump = ctx.bumps.config;
+
+    emit!(ConfigInitialized {
+        admin: config.admin,
+        report_threshold,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn set_report_threshold(ctx: Context<UpdateConfigContext>, report_threshold: u64) -> Result<()> {
+    if report_threshold == 0 {
+        return err!(TwitterError::InvalidReportThreshold);
+    }
+
+    let config = &mut ctx.accounts.config;
+    config.report_threshold = report_threshold;
+
+    emit_config_updated(config)
+}
+
+/// Limits every author to `max_actions` tweets and comments per
+/// `window_slots` slots. A window of zero slots turns rate limiting off.
+pub fn set_rate_limit(ctx: Context<UpdateConfigContext>, window_slots: u64, max_actions: u64) -> Result<()> {
+    if window_slots > 0 && max_actions == 0 {
+        return err!(TwitterError::InvalidRateLimit);
+    }
+
+    let config = &mut ctx.accounts.config;
+    config.rate_limit_window_slots = window_slots;
+    config.rate_limit_max_actions = max_actions;
+
+    emit_config_updated(config)
+}
+
+fn emit_config_updated(config: &ProgramConfig) -> Result<()> {
+    emit!(ConfigUpdated {
+        admin: config.admin,
+        report_threshold: config.report_threshold,
+        rate_limit_window_slots: config.rate_limit_window_slots,
+        rate_limit_max_actions: config.rate_limit_max_actions,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn add_moderator(ctx: Context<UpdateConfigContext>, moderator: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    if config.moderators.contains(&moderator) {
+        return err!(TwitterError::AlreadyModerator);
+    }
+    if config.moderators.len() >= MAX_MODERATORS {
+        return err!(TwitterError::MaxModeratorsReached);
+    }
+    config.moderators.push(moderator);
+
+    emit!(ModeratorAdded {
+        admin: config.admin,
+        moderator,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn remove_moderator(ctx: Context<UpdateConfigContext>, moderator: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    let position = config
+        .moderators
+        .iter()
+        .position(|key| *key == moderator)
+        .ok_or(TwitterError::ModeratorNotFound)?;
+    config.moderators.remove(position);
+
+    emit!(ModeratorRemoved {
+        admin: config.admin,
+        moderator,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeConfigContext<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ProgramConfig::INIT_SPACE + 8,
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, Twitter>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(authority.key()) @ TwitterError::NotUpgradeAuthority
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfigContext<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin,
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::instructions::publish_tweet;
+use crate::states::*;
+
+/// Creates a tweet addressed by the author's next sequence number, so an
+/// author can post any number of tweets under the same topic.
+pub fn create_tweet(ctx: Context<CreateTweetContext>, topic: String, content: String) -> Result<()> {
+    publish_tweet(
+        ctx.accounts,
+        &ctx.bumps,
+        topic,
+        content,
+        None,
+        ctx.remaining_accounts,
+    )
+}
+
+#[derive(Accounts)]
+#[instruction(topic: String, content: String)]
+pub struct CreateTweetContext<'info> {
+    #[account(mut)]
+    pub tweet_authority: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = tweet_authority,
+        space = AuthorState::INIT_SPACE + 8,
+        seeds = [
+            AUTHOR_STATE_SEED.as_bytes(),
+            tweet_authority.key().as_ref()
+        ],
+        bump
+    )]
+    pub author_state: Account<'info, AuthorState>,
+
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init,
+        payer = tweet_authority,
+        space = Tweet::space(&topic, &content) + 8,
+        seeds = [
+            TWEET_SEED.as_bytes(),
+            tweet_authority.key().as_ref(),
+            author_state.tweet_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub tweet: Account<'info, Tweet>,
+
+    #[account(
+        init_if_needed,
+        payer = tweet_authority,
+        space = TopicIndex::INIT_SPACE + 8,
+        seeds = [
+            TOPIC_INDEX_SEED.as_bytes(),
+            TopicIndex::topic_seed(&topic).as_ref()
+        ],
+        bump
+    )]
+    pub topic_index: Account<'info, TopicIndex>,
+
+    pub system_program: Program<'info, System>,
+}
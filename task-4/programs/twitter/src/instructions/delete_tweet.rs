@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::events::TweetDeleted;
+use crate::states::*;
+
+/// Closes the tweet, returns its rent to the author and drops it from the
+/// topic index.
+///
+/// Reactions and comments pointing at the tweet are left in place; their
+/// authors reclaim the rent through `reclaim_reaction` / `reclaim_comment`
+/// once the parent tweet no longer exists.
+pub fn delete_tweet(ctx: Context<DeleteTweetContext>) -> Result<()> {
+    let tweet_key = ctx.accounts.tweet.key();
+    ctx.accounts.topic_index.remove_tweet(tweet_key)?;
+
+    emit!(TweetDeleted {
+        tweet: ctx.accounts.tweet.key(),
+        tweet_author: ctx.accounts.tweet.tweet_author,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DeleteTweetContext<'info> {
+    #[account(mut)]
+    pub tweet_author: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = tweet_author,
+        close = tweet_author,
+    )]
+    pub tweet: Account<'info, Tweet>,
+
+    #[account(
+        mut,
+        seeds = [
+            TOPIC_INDEX_SEED.as_bytes(),
+            TopicIndex::topic_seed(&tweet.topic).as_ref()
+        ],
+        bump = topic_index.bump
+    )]
+    pub topic_index: Account<'info, TopicIndex>,
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+
+use crate::errors::TwitterError;
+use crate::events::TweetEdited;
+use crate::states::*;
+use crate::validation::validate_content;
+
+pub fn edit_tweet(ctx: Context<EditTweetContext>, content: String) -> Result<()> {
+    validate_content(&content)?;
+
+    let tweet = &mut ctx.accounts.tweet;
+    let now = Clock::get()?.unix_timestamp;
+
+    // Hash the content being replaced so indexers can chain the edit history.
+    let previous_content_hash = hash(tweet.content.as_bytes()).to_bytes();
+
+    tweet.edit_count = tweet
+        .edit_count
+        .checked_add(1)
+        .ok_or(TwitterError::MaxEditsReached)?;
+    tweet.last_edited_at = now;
+    tweet.set_content(content)?;
+
+    emit!(TweetEdited {
+        tweet: tweet.key(),
+        tweet_author: tweet.tweet_author,
+        previous_content_hash,
+        edit_count: tweet.edit_count,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+/// The tweet is resized to fit the new content, charging or refunding the
+/// rent difference to the author.
+#[derive(Accounts)]
+#[instruction(content: String)]
+pub struct EditTweetContext<'info> {
+    #[account(mut)]
+    pub tweet_author: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = tweet_author,
+        realloc = Tweet::space(&tweet.topic, &content) + 8,
+        realloc::payer = tweet_author,
+        realloc::zero = false,
+    )]
+    pub tweet: Account<'info, Tweet>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::events::{Followed, Unfollowed};
+use crate::states::*;
+
+pub fn follow(ctx: Context<FollowContext>) -> Result<()> {
+    let follow = &mut ctx.accounts.follow;
+
+    // `init_if_needed` lets us report a double follow with a dedicated error.
+    if follow.follower != Pubkey::default() {
+        return err!(TwitterError::AlreadyFollowing);
+    }
+
+    follow.follower = *ctx.accounts.follower.key;
+    follow.followee = ctx.accounts.followee_profile.authority;
+    follow.bump = ctx.bumps.follow;
+
+    let follower_profile = &mut ctx.accounts.follower_profile;
+    follower_profile.following_count = follower_profile
+        .following_count
+        .checked_add(1)
+        .ok_or(TwitterError::MaxFollowsReached)?;
+
+    let followee_profile = &mut ctx.accounts.followee_profile;
+    followee_profile.follower_count = followee_profile
+        .follower_count
+        .checked_add(1)
+        .ok_or(TwitterError::MaxFollowsReached)?;
+
+    emit!(Followed {
+        follower: follow.follower,
+        followee: follow.followee,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn unfollow(ctx: Context<UnfollowContext>) -> Result<()> {
+    let follower_profile = &mut ctx.accounts.follower_profile;
+    follower_profile.following_count = follower_profile
+        .following_count
+        .checked_sub(1)
+        .ok_or(TwitterError::MinFollowsReached)?;
+
+    let followee_profile = &mut ctx.accounts.followee_profile;
+    followee_profile.follower_count = followee_profile
+        .follower_count
+        .checked_sub(1)
+        .ok_or(TwitterError::MinFollowsReached)?;
+
+    emit!(Unfollowed {
+        follower: ctx.accounts.follow.follower,
+        followee: ctx.accounts.follow.followee,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FollowContext<'info> {
+    #[account(mut)]
+    pub follower: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            USER_PROFILE_SEED.as_bytes(),
+            follower.key().as_ref()
+        ],
+        bump = follower_profile.bump
+    )]
+    pub follower_profile: Account<'info, UserProfile>,
+
+    #[account(
+        mut,
+        constraint = followee_profile.authority != follower.key() @ TwitterError::CannotFollowSelf
+    )]
+    pub followee_profile: Account<'info, UserProfile>,
+
+    #[account(
+        init_if_needed,
+        payer = follower,
+        space = Follow::INIT_SPACE + 8,
+        seeds = [
+            FOLLOW_SEED.as_bytes(),
+            follower.key().as_ref(),
+            followee_profile.authority.as_ref()
+        ],
+        bump
+    )]
+    pub follow: Account<'info, Follow>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UnfollowContext<'info> {
+    #[account(mut)]
+    pub follower: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            USER_PROFILE_SEED.as_bytes(),
+            follower.key().as_ref()
+        ],
+        bump = follower_profile.bump
+    )]
+    pub follower_profile: Account<'info, UserProfile>,
+
+    #[account(
+        mut,
+        constraint = followee_profile.authority == follow.followee
+    )]
+    pub followee_profile: Account<'info, UserProfile>,
+
+    #[account(
+        mut,
+        has_one = follower,
+        close = follower,
+        seeds = [
+            FOLLOW_SEED.as_bytes(),
+            follower.key().as_ref(),
+            follow.followee.as_ref()
+        ],
+        bump = follow.bump
+    )]
+    pub follow: Account<'info, Follow>,
+}
@@ -1,68 +1,83 @@
 //-------------------------------------------------------------------------------
-///
-/// TASK: Implement the initialize tweet functionality for the Twitter program
-/// 
-/// Requirements:
-/// - Validate that topic and content don't exceed maximum lengths
-/// - Initialize a new tweet account with proper PDA seeds
-/// - Set tweet fields: topic, content, author, likes, dislikes, and bump
-/// - Initialize counters (likes and dislikes) to zero
-/// - Use topic in PDA seeds for tweet identification
-/// 
-///-------------------------------------------------------------------------------
+//
+// TASK: Implement the initialize tweet functionality for the Twitter program
+// 
+// Requirements:
+// - Validate topic and content with the shared checks in validation.rs
+// - Initialize a new tweet account with proper PDA seeds
+// - Set tweet fields: topic, content, author, reaction counters, and bump
+// - Initialize the per-reaction counters to zero
+// - Use the author's tweet sequence number in PDA seeds for tweet identification
+// - Store the #hashtags and emit an event for every @mention backed by a
+//   `Handle` account passed in remaining accounts
+// 
+// NOTE: This instruction used to seed tweets by their topic, which allowed
+// one tweet per topic per author and let a deleted tweet's address be reused.
+// It now takes the same accounts as `create_tweet` and calls it; every tweet
+// creation path publishes through `publish_tweet` below. Tweets
+// created under the old topic seeds use an older layout that no other
+// instruction can read; `migrate_legacy_tweet` moves them to a
+// sequence-numbered address.
+// 
+//-------------------------------------------------------------------------------
 
 use anchor_lang::prelude::*;
 
-use crate::errors::TwitterError;
-use crate::states::*;
+use crate::content::{emit_mentions, extract_mentions};
+use crate::events::TweetCreated;
+use crate::instructions::{CreateTweetContext, CreateTweetContextBumps};
+use crate::validation::{validate_content, validate_topic};
 
-pub fn initialize_tweet(
-    ctx: Context<InitializeTweet>,
+/// Publishes a new tweet, shared by `initialize`, `create_tweet` and
+/// `quote_tweet` so every path validates, rate limits, indexes and announces
+/// tweets the same way.
+pub fn publish_tweet(
+    accounts: &mut CreateTweetContext,
+    bumps: &CreateTweetContextBumps,
     topic: String,
     content: String,
+    quoted_tweet: Option<Pubkey>,
+    remaining_accounts: &[AccountInfo],
 ) -> Result<()> {
-    // TODO: Implement initialize tweet functionality
-    //todo!()
-     // 1. Validate that topic and content don't exceed the maximum lengths.
-    if topic.len() > TOPIC_LENGTH {
-        return err!(TwitterError::TopicTooLong);
-    }
-    if content.len() > CONTENT_LENGTH {
-        return err!(TwitterError::ContentTooLong);
-    }
+    // 1. Validate the topic and content with the shared checks.
+    validate_topic(&topic)?;
+    validate_content(&content)?;
 
-    // 2. Get the tweet account from the context.
-    let tweet = &mut ctx.accounts.tweet;
+    // 2. Parse the @mentions before the content is moved into the tweet.
+    let mentions = extract_mentions(&content)?;
 
-    // 3. Set the tweet fields.
-    tweet.tweet_author = *ctx.accounts.tweet_authority.key;
-    tweet.topic = topic;
-    tweet.content = content;
-    tweet.likes = 0;
-    tweet.dislikes = 0;
-    tweet.bump = ctx.bumps.tweet;
+    // 3. Count the tweet against the author's rate limit and hand out its
+    //    sequence number.
+    let author_state = &mut accounts.author_state;
+    author_state.initialize_if_new(*accounts.tweet_authority.key, bumps.author_state);
+    author_state.record_action(&accounts.config, Clock::get()?.slot)?;
+    let tweet_id = author_state.register_tweet()?;
 
-    Ok(())
-}
+    // 4. Set the tweet fields, hashtags are extracted from the content.
+    let tweet = &mut accounts.tweet;
+    tweet.initialize(
+        *accounts.tweet_authority.key,
+        tweet_id,
+        topic,
+        content,
+        quoted_tweet,
+        bumps.tweet,
+    )?;
 
-#[derive(Accounts)]
-#[instruction(topic: String)]
-pub struct InitializeTweet<'info> {
-    // TODO: Add required account constraints
-    #[account(mut)]
-    pub tweet_authority: Signer<'info>,
+    // 5. Record the tweet in its topic feed.
+    let topic_index = &mut accounts.topic_index;
+    topic_index.initialize_if_new(&tweet.topic, bumps.topic_index);
+    topic_index.push_tweet(tweet.key())?;
 
-    #[account(
-        init,
-        payer = tweet_authority,
-        space = Tweet::INIT_SPACE + 8,
-        seeds = [
-            topic.as_bytes(),
-            TWEET_SEED.as_bytes(),
-            tweet_authority.key().as_ref()
-        ],
-        bump
-    )]
-    pub tweet: Account<'info, Tweet>,
-    pub system_program: Program<'info, System>,
+    // 6. Let indexers and notification services know about the new tweet.
+    emit!(TweetCreated {
+        tweet: tweet.key(),
+        tweet_author: tweet.tweet_author,
+        topic: tweet.topic.clone(),
+        quoted_tweet,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    emit_mentions(tweet.key(), tweet.tweet_author, &mentions, remaining_accounts)?;
+
+    Ok(())
 }
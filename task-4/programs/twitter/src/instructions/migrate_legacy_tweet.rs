@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+
+use crate::events::TweetMigrated;
+use crate::states::*;
+
+/// Moves a tweet created under the old topic seeds to the author's next
+/// sequence-numbered address and closes the old account, refunding its rent
+/// to the author.
+///
+/// The likes and dislikes carry over. Comments and reactions made on the
+/// legacy tweet stay at their old addresses. The legacy layout has no
+/// creation time, so the migration time is recorded instead.
+pub fn migrate_legacy_tweet(ctx: Context<MigrateLegacyTweetContext>, _topic: String) -> Result<()> {
+    let legacy_tweet = LegacyTweet::try_from_account(&ctx.accounts.legacy_tweet)?;
+
+    let author_state = &mut ctx.accounts.author_state;
+    author_state.initialize_if_new(*ctx.accounts.tweet_author.key, ctx.bumps.author_state);
+    let tweet_id = author_state.register_tweet()?;
+
+    let tweet = &mut ctx.accounts.tweet;
+    tweet.initialize(
+        legacy_tweet.tweet_author,
+        tweet_id,
+        legacy_tweet.topic,
+        legacy_tweet.content,
+        None,
+        ctx.bumps.tweet,
+    )?;
+    tweet.reactions[ReactionType::Like as usize] = legacy_tweet.likes;
+    tweet.reactions[ReactionType::Dislike as usize] = legacy_tweet.dislikes;
+
+    let topic_index = &mut ctx.accounts.topic_index;
+    topic_index.initialize_if_new(&tweet.topic, ctx.bumps.topic_index);
+    topic_index.push_tweet(tweet.key())?;
+
+    let legacy_account = ctx.accounts.legacy_tweet.to_account_info();
+    let author_account = ctx.accounts.tweet_author.to_account_info();
+    **author_account.try_borrow_mut_lamports()? += legacy_account.lamports();
+    **legacy_account.try_borrow_mut_lamports()? = 0;
+    legacy_account.assign(&System::id());
+    legacy_account.resize(0)?;
+
+    emit!(TweetMigrated {
+        legacy_tweet: legacy_account.key(),
+        tweet: tweet.key(),
+        tweet_author: tweet.tweet_author,
+        tweet_id,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(topic: String)]
+pub struct MigrateLegacyTweetContext<'info> {
+    #[account(mut)]
+    pub tweet_author: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = tweet_author,
+        space = AuthorState::INIT_SPACE + 8,
+        seeds = [
+            AUTHOR_STATE_SEED.as_bytes(),
+            tweet_author.key().as_ref()
+        ],
+        bump
+    )]
+    pub author_state: Account<'info, AuthorState>,
+
+    /// CHECK: Holds the legacy `Tweet` layout, which no longer deserializes as
+    /// a `Tweet`; it is read through `LegacyTweet` and closed in the handler.
+    /// Only `initialize` ever wrote to these seeds, always with the signer as
+    /// the author.
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [
+            topic.as_bytes(),
+            TWEET_SEED.as_bytes(),
+            tweet_author.key().as_ref()
+        ],
+        bump
+    )]
+    pub legacy_tweet: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = tweet_author,
+        space = LegacyTweet::try_from_account(&legacy_tweet)?.migrated_space() + 8,
+        seeds = [
+            TWEET_SEED.as_bytes(),
+            tweet_author.key().as_ref(),
+            author_state.tweet_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub tweet: Account<'info, Tweet>,
+
+    #[account(
+        init_if_needed,
+        payer = tweet_author,
+        space = TopicIndex::INIT_SPACE + 8,
+        seeds = [
+            TOPIC_INDEX_SEED.as_bytes(),
+            TopicIndex::topic_seed(&topic).as_ref()
+        ],
+        bump
+    )]
+    pub topic_index: Account<'info, TopicIndex>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -1,14 +1,68 @@
+pub use config::*;
+pub mod config;
+
+pub use moderation::*;
+pub mod moderation;
+
+pub use report::*;
+pub mod report;
+
 pub use initialize_tweet::*;
 pub mod initialize_tweet;
 
+pub use create_tweet::*;
+pub mod create_tweet;
+
+pub use migrate_legacy_tweet::*;
+pub mod migrate_legacy_tweet;
+
+pub use edit_tweet::*;
+pub mod edit_tweet;
+
+pub use delete_tweet::*;
+pub mod delete_tweet;
+
+pub use retweet::*;
+pub mod retweet;
+
+pub use tip_tweet::*;
+pub mod tip_tweet;
+
+pub use tip_tweet_token::*;
+pub mod tip_tweet_token;
+
+pub use bookmark::*;
+pub mod bookmark;
+
+pub use poll::*;
+pub mod poll;
+
 pub use add_reaction::*;
 pub mod add_reaction;
 
 pub use remove_reaction::*;
 pub mod remove_reaction;
 
+pub use change_reaction::*;
+pub mod change_reaction;
+
 pub use add_comment::*;
 pub mod add_comment;
 
+pub use reply_to_comment::*;
+pub mod reply_to_comment;
+
 pub use remove_comment::*;
 pub mod remove_comment;
+
+pub use reclaim_orphan::*;
+pub mod reclaim_orphan;
+
+pub use user_profile::*;
+pub mod user_profile;
+
+pub use follow::*;
+pub mod follow;
+
+pub use block::*;
+pub mod block;
@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::events::{CommentHidden, CommentUnhidden, ReviewDismissed, TweetHidden, TweetUnhidden};
+use crate::states::*;
+
+/// Flags a tweet as hidden, which also resolves any pending review. The
+/// account stays open so the author keeps ownership and can still delete it.
+pub fn hide_tweet(ctx: Context<HideTweetContext>, reason: ModerationReason) -> Result<()> {
+    let tweet = &mut ctx.accounts.tweet;
+    tweet.hidden_reason = Some(reason);
+    tweet.under_review = false;
+
+    emit!(TweetHidden {
+        tweet: tweet.key(),
+        moderator: *ctx.accounts.moderator.key,
+        reason,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Resolves a pending review without hiding the tweet. The reports stay
+/// counted, so the next report puts the tweet back under review.
+pub fn dismiss_review(ctx: Context<HideTweetContext>) -> Result<()> {
+    let tweet = &mut ctx.accounts.tweet;
+    require!(tweet.under_review, TwitterError::NotUnderReview);
+    tweet.under_review = false;
+
+    emit!(ReviewDismissed {
+        tweet: tweet.key(),
+        moderator: *ctx.accounts.moderator.key,
+        report_count: tweet.report_count,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn hide_comment(ctx: Context<HideCommentContext>, reason: ModerationReason) -> Result<()> {
+    let comment = &mut ctx.accounts.comment;
+    comment.hidden_reason = Some(reason);
+
+    emit!(CommentHidden {
+        comment: comment.key(),
+        tweet: comment.parent_tweet,
+        moderator: *ctx.accounts.moderator.key,
+        reason,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Restores a hidden tweet, for example after a successful appeal.
+pub fn unhide_tweet(ctx: Context<HideTweetContext>) -> Result<()> {
+    let tweet = &mut ctx.accounts.tweet;
+    require!(tweet.hidden_reason.is_some(), TwitterError::NotHidden);
+    tweet.hidden_reason = None;
+
+    emit!(TweetUnhidden {
+        tweet: tweet.key(),
+        moderator: *ctx.accounts.moderator.key,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn unhide_comment(ctx: Context<HideCommentContext>) -> Result<()> {
+    let comment = &mut ctx.accounts.comment;
+    require!(comment.hidden_reason.is_some(), TwitterError::NotHidden);
+    comment.hidden_reason = None;
+
+    emit!(CommentUnhidden {
+        comment: comment.key(),
+        tweet: comment.parent_tweet,
+        moderator: *ctx.accounts.moderator.key,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct HideTweetContext<'info> {
+    #[account(
+        constraint = config.is_moderator(moderator.key) @ TwitterError::NotModerator
+    )]
+    pub moderator: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(mut)]
+    pub tweet: Account<'info, Tweet>,
+}
+
+#[derive(Accounts)]
+pub struct HideCommentContext<'info> {
+    #[account(
+        constraint = config.is_moderator(moderator.key) @ TwitterError::NotModerator
+    )]
+    pub moderator: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(mut)]
+    pub comment: Account<'info, Comment>,
+}
@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::events::{PollCreated, PollVoted};
+use crate::states::*;
+use crate::validation::validate_poll_options;
+
+pub fn create_poll(ctx: Context<CreatePollContext>, options: Vec<String>, end_timestamp: i64) -> Result<()> {
+    validate_poll_options(&options)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    if end_timestamp <= now {
+        return err!(TwitterError::PollEndInPast);
+    }
+
+    let poll = &mut ctx.accounts.poll;
+    poll.tweet = ctx.accounts.tweet.key();
+    poll.tweet_author = *ctx.accounts.tweet_author.key;
+    poll.options = options;
+    poll.tallies = [0; MAX_POLL_OPTIONS];
+    poll.end_timestamp = end_timestamp;
+    poll.bump = ctx.bumps.poll;
+
+    emit!(PollCreated {
+        poll: poll.key(),
+        tweet: poll.tweet,
+        tweet_author: poll.tweet_author,
+        end_timestamp,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+pub fn vote_poll(ctx: Context<VotePollContext>, option_index: u8) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let poll = &mut ctx.accounts.poll;
+
+    if now >= poll.end_timestamp {
+        return err!(TwitterError::PollClosed);
+    }
+    if option_index as usize >= poll.options.len() {
+        return err!(TwitterError::InvalidPollOption);
+    }
+
+    let tally = &mut poll.tallies[option_index as usize];
+    *tally = tally.checked_add(1).ok_or(TwitterError::MaxVotesReached)?;
+
+    let poll_vote = &mut ctx.accounts.poll_vote;
+    poll_vote.poll = poll.key();
+    poll_vote.voter = *ctx.accounts.voter.key;
+    poll_vote.option_index = option_index;
+    poll_vote.bump = ctx.bumps.poll_vote;
+
+    emit!(PollVoted {
+        poll: poll_vote.poll,
+        voter: poll_vote.voter,
+        option_index,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreatePollContext<'info> {
+    #[account(mut)]
+    pub tweet_author: Signer<'info>,
+
+    #[account(has_one = tweet_author)]
+    pub tweet: Account<'info, Tweet>,
+
+    #[account(
+        init,
+        payer = tweet_author,
+        space = Poll::INIT_SPACE + 8,
+        seeds = [
+            POLL_SEED.as_bytes(),
+            tweet.key().as_ref()
+        ],
+        bump
+    )]
+    pub poll: Account<'info, Poll>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct VotePollContext<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            POLL_SEED.as_bytes(),
+            poll.tweet.as_ref()
+        ],
+        bump = poll.bump
+    )]
+    pub poll: Account<'info, Poll>,
+
+    #[account(
+        init,
+        payer = voter,
+        space = PollVote::INIT_SPACE + 8,
+        seeds = [
+            POLL_VOTE_SEED.as_bytes(),
+            poll.key().as_ref(),
+            voter.key().as_ref()
+        ],
+        bump
+    )]
+    pub poll_vote: Account<'info, PollVote>,
+
+    pub system_program: Program<'info, System>,
+}
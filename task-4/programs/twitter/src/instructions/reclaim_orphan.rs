@@ -0,0 +1,222 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::events::{CommentRemoved, ReactionRemoved, RetweetUndone};
+use crate::states::*;
+
+/// Closes a reaction whose parent tweet has been deleted. No counters are
+/// touched since the tweet holding them is already gone.
+pub fn reclaim_reaction(ctx: Context<ReclaimReactionContext>) -> Result<()> {
+    let tweet_reaction = &ctx.accounts.tweet_reaction;
+
+    emit!(ReactionRemoved {
+        reaction: tweet_reaction.key(),
+        reaction_author: tweet_reaction.reaction_author,
+        tweet: tweet_reaction.parent_tweet,
+        reaction_type: tweet_reaction.reaction,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Closes a comment whose parent tweet has been deleted.
+pub fn reclaim_comment(ctx: Context<ReclaimCommentContext>) -> Result<()> {
+    let comment = &ctx.accounts.comment;
+
+    emit!(CommentRemoved {
+        comment: comment.key(),
+        comment_author: comment.comment_author,
+        tweet: comment.parent_tweet,
+        comment_id: comment.comment_id,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Closes a retweet whose original tweet has been deleted.
+pub fn reclaim_retweet(ctx: Context<ReclaimRetweetContext>) -> Result<()> {
+    let retweet = &ctx.accounts.retweet;
+
+    emit!(RetweetUndone {
+        retweet: retweet.key(),
+        retweeter: retweet.retweeter,
+        original_tweet: retweet.original_tweet,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Closes a poll whose tweet has been deleted, refunding the tweet author.
+pub fn reclaim_poll(_ctx: Context<ReclaimPollContext>) -> Result<()> {
+    Ok(())
+}
+
+/// Closes a vote once its poll has ended or has been reclaimed. The tallies
+/// live on the poll, so the result is unaffected.
+pub fn reclaim_poll_vote(ctx: Context<ReclaimPollVoteContext>) -> Result<()> {
+    let poll = &ctx.accounts.poll;
+    if poll.data_is_empty() {
+        return Ok(());
+    }
+    require_keys_eq!(*poll.owner, crate::ID);
+
+    let data = poll.try_borrow_data()?;
+    let poll = Poll::try_deserialize(&mut &data[..])?;
+    if Clock::get()?.unix_timestamp < poll.end_timestamp {
+        return err!(TwitterError::PollStillOpen);
+    }
+
+    Ok(())
+}
+
+/// Closes a report whose tweet has been deleted, refunding the reporter.
+pub fn reclaim_report(_ctx: Context<ReclaimReportContext>) -> Result<()> {
+    Ok(())
+}
+
+/// Closes the token tip ledger of a deleted tweet, refunding whoever paid
+/// for it.
+pub fn reclaim_tip_ledger(_ctx: Context<ReclaimTipLedgerContext>) -> Result<()> {
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReclaimReactionContext<'info> {
+    #[account(mut)]
+    pub reaction_author: Signer<'info>,
+
+    /// CHECK: only used to prove the parent tweet was closed.
+    #[account(
+        address = tweet_reaction.parent_tweet,
+        constraint = tweet.data_is_empty() @ TwitterError::ParentTweetStillExists
+    )]
+    pub tweet: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = reaction_author,
+        has_one = reaction_author,
+    )]
+    pub tweet_reaction: Account<'info, Reaction>,
+}
+
+#[derive(Accounts)]
+pub struct ReclaimCommentContext<'info> {
+    #[account(mut)]
+    pub comment_author: Signer<'info>,
+
+    /// CHECK: only used to prove the parent tweet was closed.
+    #[account(
+        address = comment.parent_tweet,
+        constraint = tweet.data_is_empty() @ TwitterError::ParentTweetStillExists
+    )]
+    pub tweet: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = comment_author,
+        has_one = comment_author,
+    )]
+    pub comment: Account<'info, Comment>,
+}
+
+#[derive(Accounts)]
+pub struct ReclaimRetweetContext<'info> {
+    #[account(mut)]
+    pub retweeter: Signer<'info>,
+
+    /// CHECK: only used to prove the original tweet was closed.
+    #[account(
+        address = retweet.original_tweet,
+        constraint = original_tweet.data_is_empty() @ TwitterError::ParentTweetStillExists
+    )]
+    pub original_tweet: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = retweeter,
+        has_one = retweeter,
+    )]
+    pub retweet: Account<'info, Retweet>,
+}
+
+#[derive(Accounts)]
+pub struct ReclaimPollContext<'info> {
+    #[account(mut)]
+    pub tweet_author: Signer<'info>,
+
+    /// CHECK: only used to prove the tweet was closed.
+    #[account(
+        address = poll.tweet,
+        constraint = tweet.data_is_empty() @ TwitterError::ParentTweetStillExists
+    )]
+    pub tweet: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = tweet_author,
+        has_one = tweet_author,
+    )]
+    pub poll: Account<'info, Poll>,
+}
+
+#[derive(Accounts)]
+pub struct ReclaimPollVoteContext<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    /// CHECK: must match `poll_vote.poll`. Read in the handler because the
+    /// poll may already be closed.
+    #[account(address = poll_vote.poll)]
+    pub poll: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = voter,
+        has_one = voter,
+    )]
+    pub poll_vote: Account<'info, PollVote>,
+}
+
+#[derive(Accounts)]
+pub struct ReclaimReportContext<'info> {
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+
+    /// CHECK: only used to prove the tweet was closed.
+    #[account(
+        address = report.tweet,
+        constraint = tweet.data_is_empty() @ TwitterError::ParentTweetStillExists
+    )]
+    pub tweet: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = reporter,
+        has_one = reporter,
+    )]
+    pub report: Account<'info, Report>,
+}
+
+#[derive(Accounts)]
+pub struct ReclaimTipLedgerContext<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: only used to prove the tweet was closed.
+    #[account(
+        address = tip_ledger.tweet,
+        constraint = tweet.data_is_empty() @ TwitterError::ParentTweetStillExists
+    )]
+    pub tweet: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = payer,
+        has_one = payer,
+    )]
+    pub tip_ledger: Account<'info, TipLedger>,
+}
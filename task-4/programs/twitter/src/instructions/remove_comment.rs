@@ -1,26 +1,75 @@
 //-------------------------------------------------------------------------------
-///
-/// TASK: Implement the remove comment functionality for the Twitter program
-/// 
-/// Requirements:
-/// - Close the comment account and return rent to comment author
-/// 
-/// NOTE: No implementation logic is needed in the function body - this 
-/// functionality is achieved entirely through account constraints!
-/// 
-///-------------------------------------------------------------------------------
+//
+// TASK: Implement the remove comment functionality for the Twitter program
+// 
+// Requirements:
+// - Close the comment account and return rent to comment author
+// - Decrement the comment counter on the parent tweet
+// - Decrement the reply counter on the parent comment, if it still exists
+// 
+// NOTE: Closing the account is achieved entirely through account constraints,
+// the function body only keeps the tweet and parent comment counters in sync.
+// The comment PDA is derived from its stored `comment_id`, so removal only
+// needs the comment's address, never its content.
+// 
+//-------------------------------------------------------------------------------
 
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::hash::hash;
 
+use crate::errors::TwitterError;
+use crate::events::CommentRemoved;
 use crate::states::*;
 
-pub fn remove_comment(_ctx: Context<RemoveCommentContext>, _comment_content: String) -> Result<()> {
+pub fn remove_comment(ctx: Context<RemoveCommentContext>) -> Result<()> {
+    let tweet = &mut ctx.accounts.tweet;
+
+    if tweet.comment_count == 0 {
+        return err!(TwitterError::MinCommentsReached);
+    }
+    tweet.comment_count -= 1;
+
+    let comment = &ctx.accounts.comment;
+    emit!(CommentRemoved {
+        comment: comment.key(),
+        comment_author: comment.comment_author,
+        tweet: tweet.key(),
+        comment_id: comment.comment_id,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    let Some(parent_key) = comment.parent_comment else {
+        return Ok(());
+    };
+    let parent_comment = ctx
+        .accounts
+        .parent_comment
+        .as_ref()
+        .ok_or(TwitterError::MissingParentComment)?;
+    require_keys_eq!(
+        parent_comment.key(),
+        parent_key,
+        TwitterError::CommentParentMismatch
+    );
+
+    // The parent may have been removed before its replies, in which case
+    // there is no reply counter left to update.
+    if parent_comment.data_is_empty() {
+        return Ok(());
+    }
+    require_keys_eq!(*parent_comment.owner, crate::ID);
+
+    let mut data = parent_comment.try_borrow_mut_data()?;
+    let mut parent = Comment::try_deserialize(&mut &data[..])?;
+    parent.reply_count = parent
+        .reply_count
+        .checked_sub(1)
+        .ok_or(TwitterError::MinCommentsReached)?;
+    parent.try_serialize(&mut &mut data[..])?;
+
     Ok(())
 }
 
 #[derive(Accounts)]
-#[instruction(comment_content: String)]
 pub struct RemoveCommentContext<'info> {
     // TODO: Add required account constraints
      #[account(mut)]
@@ -32,13 +81,18 @@ pub struct RemoveCommentContext<'info> {
         close = comment_author,
         seeds = [
             COMMENT_SEED.as_bytes(),
-            comment_author.key().as_ref(),
-            hash(comment_content.as_bytes()).as_ref(),
-            tweet.key().as_ref()
+            tweet.key().as_ref(),
+            comment.comment_id.to_le_bytes().as_ref()
         ],
         bump = comment.bump
     )]
     pub comment: Account<'info, Comment>,
 
+    #[account(mut)]
     pub tweet: Account<'info, Tweet>,
+
+    /// CHECK: must match `comment.parent_comment`. Validated in the handler
+    /// because the parent comment may already be closed.
+    #[account(mut)]
+    pub parent_comment: Option<UncheckedAccount<'info>>,
 }
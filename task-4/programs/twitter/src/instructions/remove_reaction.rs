@@ -1,17 +1,17 @@
 //-------------------------------------------------------------------------------
-///
-/// TASK: Implement the remove reaction functionality for the Twitter program
-/// 
-/// Requirements:
-/// - Verify that the tweet reaction exists and belongs to the reaction author
-/// - Decrement the appropriate counter (likes or dislikes) on the tweet
-/// - Close the tweet reaction account and return rent to reaction author
-/// 
-///-------------------------------------------------------------------------------
+//
+// TASK: Implement the remove reaction functionality for the Twitter program
+// 
+// Requirements:
+// - Verify that the tweet reaction exists and belongs to the reaction author
+// - Decrement the counter of the removed reaction type on the tweet
+// - Close the tweet reaction account and return rent to reaction author
+// 
+//-------------------------------------------------------------------------------
 
 use anchor_lang::prelude::*;
 
-use crate::errors::TwitterError;
+use crate::events::ReactionRemoved;
 use crate::states::*;
 
 pub fn remove_reaction(ctx: Context<RemoveReactionContext>) -> Result<()> {
@@ -21,20 +21,15 @@ pub fn remove_reaction(ctx: Context<RemoveReactionContext>) -> Result<()> {
     let tweet_reaction = &ctx.accounts.tweet_reaction;
 
     // 1. Decrement the appropriate counter on the tweet
-    match tweet_reaction.reaction {
-        ReactionType::Like => {
-            if tweet.likes == 0 {
-                return err!(TwitterError::MinLikesReached);
-            }
-            tweet.likes -= 1;
-        }
-        ReactionType::Dislike => {
-            if tweet.dislikes == 0 {
-                return err!(TwitterError::MinDislikesReached);
-            }
-            tweet.dislikes -= 1;
-        }
-    }
+    tweet.decrement_reaction(tweet_reaction.reaction)?;
+
+    emit!(ReactionRemoved {
+        reaction: tweet_reaction.key(),
+        reaction_author: tweet_reaction.reaction_author,
+        tweet: tweet.key(),
+        reaction_type: tweet_reaction.reaction,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
 
     // 2. The tweet reaction account is automatically closed and rent returned
     // to the reaction_author due to the `close` constraint.
@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+
+use crate::errors::TwitterError;
+use crate::events::CommentAdded;
+use crate::states::*;
+use crate::validation::validate_comment;
+
+pub fn reply_to_comment(
+    ctx: Context<ReplyToCommentContext>,
+    comment_content: String,
+) -> Result<()> {
+    validate_comment(&comment_content)?;
+
+    let parent_comment = &mut ctx.accounts.parent_comment;
+    let depth = parent_comment.depth + 1;
+    if depth > MAX_REPLY_DEPTH {
+        return err!(TwitterError::ReplyDepthExceeded);
+    }
+    parent_comment.reply_count = parent_comment
+        .reply_count
+        .checked_add(1)
+        .ok_or(TwitterError::MaxCommentsReached)?;
+
+    let clock = Clock::get()?;
+    let author_state = &mut ctx.accounts.author_state;
+    author_state.initialize_if_new(*ctx.accounts.comment_author.key, ctx.bumps.author_state);
+    author_state.record_action(&ctx.accounts.config, clock.slot)?;
+
+    let parent_tweet = &mut ctx.accounts.parent_tweet;
+    let comment = &mut ctx.accounts.comment;
+
+    comment.comment_author = *ctx.accounts.comment_author.key;
+    comment.parent_tweet = parent_tweet.key();
+    comment.comment_id = parent_tweet.register_comment()?;
+    comment.parent_comment = Some(parent_comment.key());
+    comment.reply_count = 0;
+    comment.depth = depth;
+    comment.content_hash = hash(comment_content.as_bytes()).to_bytes();
+    comment.content = comment_content;
+    comment.hidden_reason = None;
+    comment.created_at = clock.unix_timestamp;
+    comment.created_slot = clock.slot;
+    comment.bump = ctx.bumps.comment;
+
+    emit!(CommentAdded {
+        comment: comment.key(),
+        comment_author: comment.comment_author,
+        tweet: comment.parent_tweet,
+        comment_id: comment.comment_id,
+        parent_comment: comment.parent_comment,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(comment_content: String)]
+pub struct ReplyToCommentContext<'info> {
+    #[account(mut)]
+    pub comment_author: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = comment_author,
+        space = AuthorState::INIT_SPACE + 8,
+        seeds = [
+            AUTHOR_STATE_SEED.as_bytes(),
+            comment_author.key().as_ref()
+        ],
+        bump
+    )]
+    pub author_state: Account<'info, AuthorState>,
+
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init,
+        payer = comment_author,
+        space = Comment::space(&comment_content) + 8,
+        seeds = [
+            COMMENT_SEED.as_bytes(),
+            parent_tweet.key().as_ref(),
+            parent_tweet.next_comment_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub comment: Account<'info, Comment>,
+
+    #[account(
+        mut,
+        constraint = parent_comment.parent_tweet == parent_tweet.key() @ TwitterError::CommentParentMismatch
+    )]
+    pub parent_comment: Account<'info, Comment>,
+
+    #[account(mut)]
+    pub parent_tweet: Account<'info, Tweet>,
+
+    /// CHECK: block PDA of the tweet author against the comment author, which must not exist.
+    #[account(
+        seeds = [
+            BLOCK_SEED.as_bytes(),
+            parent_tweet.tweet_author.as_ref(),
+            comment_author.key().as_ref()
+        ],
+        bump,
+        constraint = author_block.data_is_empty() @ TwitterError::Blocked
+    )]
+    pub author_block: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::events::{TweetReported, TweetUnderReview};
+use crate::states::*;
+
+pub fn report_tweet(ctx: Context<ReportTweetContext>, reason: ModerationReason) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    let report = &mut ctx.accounts.report;
+    report.reporter = *ctx.accounts.reporter.key;
+    report.tweet = ctx.accounts.tweet.key();
+    report.reason = reason;
+    report.bump = ctx.bumps.report;
+
+    let tweet = &mut ctx.accounts.tweet;
+    tweet.report_count = tweet
+        .report_count
+        .checked_add(1)
+        .ok_or(TwitterError::MaxReportsReached)?;
+
+    emit!(TweetReported {
+        report: report.key(),
+        tweet: report.tweet,
+        reporter: report.reporter,
+        reason,
+        timestamp: now,
+    });
+
+    // Tweets a moderator already hid do not need another review.
+    if !tweet.under_review
+        && tweet.hidden_reason.is_none()
+        && tweet.report_count >= ctx.accounts.config.report_threshold
+    {
+        tweet.under_review = true;
+
+        emit!(TweetUnderReview {
+            tweet: tweet.key(),
+            report_count: tweet.report_count,
+            timestamp: now,
+        });
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReportTweetContext<'info> {
+    #[account(
+        mut,
+        constraint = reporter.key() != tweet.tweet_author @ TwitterError::CannotReportSelf
+    )]
+    pub reporter: Signer<'info>,
+
+    #[account(mut)]
+    pub tweet: Account<'info, Tweet>,
+
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init,
+        payer = reporter,
+        space = Report::INIT_SPACE + 8,
+        seeds = [
+            REPORT_SEED.as_bytes(),
+            tweet.key().as_ref(),
+            reporter.key().as_ref()
+        ],
+        bump
+    )]
+    pub report: Account<'info, Report>,
+
+    pub system_program: Program<'info, System>,
+}
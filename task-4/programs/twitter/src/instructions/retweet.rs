@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::events::{Retweeted, RetweetUndone};
+use crate::instructions::create_tweet::*;
+use crate::instructions::publish_tweet;
+use crate::states::*;
+
+pub fn retweet(ctx: Context<RetweetContext>) -> Result<()> {
+    let original_tweet = &mut ctx.accounts.original_tweet;
+    let retweet = &mut ctx.accounts.retweet;
+
+    original_tweet.retweet_count = original_tweet
+        .retweet_count
+        .checked_add(1)
+        .ok_or(TwitterError::MaxRetweetsReached)?;
+
+    retweet.retweeter = *ctx.accounts.retweeter.key;
+    retweet.original_tweet = original_tweet.key();
+    retweet.bump = ctx.bumps.retweet;
+
+    emit!(Retweeted {
+        retweet: retweet.key(),
+        retweeter: retweet.retweeter,
+        original_tweet: retweet.original_tweet,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn undo_retweet(ctx: Context<UndoRetweetContext>) -> Result<()> {
+    let original_tweet = &mut ctx.accounts.original_tweet;
+
+    original_tweet.retweet_count = original_tweet
+        .retweet_count
+        .checked_sub(1)
+        .ok_or(TwitterError::MinRetweetsReached)?;
+
+    emit!(RetweetUndone {
+        retweet: ctx.accounts.retweet.key(),
+        retweeter: ctx.accounts.retweet.retweeter,
+        original_tweet: original_tweet.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Creates a new tweet that references `quoted_tweet`. Unlike a plain
+/// retweet this does not touch the quoted tweet's `retweet_count`.
+pub fn quote_tweet(ctx: Context<QuoteTweetContext>, topic: String, content: String) -> Result<()> {
+    let quoted_tweet = ctx.accounts.quoted_tweet.key();
+    publish_tweet(
+        &mut ctx.accounts.new_tweet,
+        &ctx.bumps.new_tweet,
+        topic,
+        content,
+        Some(quoted_tweet),
+        ctx.remaining_accounts,
+    )
+}
+
+#[derive(Accounts)]
+pub struct RetweetContext<'info> {
+    #[account(mut)]
+    pub retweeter: Signer<'info>,
+
+    #[account(mut)]
+    pub original_tweet: Account<'info, Tweet>,
+
+    #[account(
+        init,
+        payer = retweeter,
+        space = Retweet::INIT_SPACE + 8,
+        seeds = [
+            RETWEET_SEED.as_bytes(),
+            retweeter.key().as_ref(),
+            original_tweet.key().as_ref()
+        ],
+        bump
+    )]
+    pub retweet: Account<'info, Retweet>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UndoRetweetContext<'info> {
+    #[account(mut)]
+    pub retweeter: Signer<'info>,
+
+    #[account(mut)]
+    pub original_tweet: Account<'info, Tweet>,
+
+    #[account(
+        mut,
+        has_one = retweeter,
+        close = retweeter,
+        seeds = [
+            RETWEET_SEED.as_bytes(),
+            retweeter.key().as_ref(),
+            original_tweet.key().as_ref()
+        ],
+        bump = retweet.bump
+    )]
+    pub retweet: Account<'info, Retweet>,
+}
+
+#[derive(Accounts)]
+pub struct QuoteTweetContext<'info> {
+    pub new_tweet: CreateTweetContext<'info>,
+
+    pub quoted_tweet: Account<'info, Tweet>,
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::errors::TwitterError;
+use crate::events::TweetTipped;
+use crate::states::*;
+
+pub fn tip_tweet(ctx: Context<TipTweetContext>, amount: u64) -> Result<()> {
+    if amount == 0 {
+        return err!(TwitterError::ZeroTipAmount);
+    }
+
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.tipper.to_account_info(),
+                to: ctx.accounts.tweet_author.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let tweet = &mut ctx.accounts.tweet;
+    tweet.tips_received = tweet
+        .tips_received
+        .checked_add(amount)
+        .ok_or(TwitterError::MaxTipsReached)?;
+
+    emit!(TweetTipped {
+        tweet: tweet.key(),
+        tipper: *ctx.accounts.tipper.key,
+        tweet_author: tweet.tweet_author,
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct TipTweetContext<'info> {
+    #[account(
+        mut,
+        constraint = tipper.key() != tweet.tweet_author @ TwitterError::CannotTipSelf
+    )]
+    pub tipper: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = tweet_author,
+    )]
+    pub tweet: Account<'info, Tweet>,
+
+    #[account(mut)]
+    pub tweet_author: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::errors::TwitterError;
+use crate::events::TweetTokenTipped;
+use crate::states::*;
+
+/// Tips the tweet author in any SPL Token or Token-2022 mint, creating the
+/// author's associated token account on first use.
+pub fn tip_tweet_token(ctx: Context<TipTweetTokenContext>, amount: u64) -> Result<()> {
+    if amount == 0 {
+        return err!(TwitterError::ZeroTipAmount);
+    }
+
+    transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.tipper_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.author_token_account.to_account_info(),
+                authority: ctx.accounts.tipper.to_account_info(),
+            },
+        ),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    let tip_ledger = &mut ctx.accounts.tip_ledger;
+    if tip_ledger.tweet == Pubkey::default() {
+        tip_ledger.tweet = ctx.accounts.tweet.key();
+        tip_ledger.mint = ctx.accounts.mint.key();
+        tip_ledger.payer = *ctx.accounts.tipper.key;
+        tip_ledger.bump = ctx.bumps.tip_ledger;
+    }
+    tip_ledger.total_amount = tip_ledger
+        .total_amount
+        .checked_add(amount)
+        .ok_or(TwitterError::MaxTipsReached)?;
+    tip_ledger.tip_count = tip_ledger
+        .tip_count
+        .checked_add(1)
+        .ok_or(TwitterError::MaxTipsReached)?;
+
+    emit!(TweetTokenTipped {
+        tweet: tip_ledger.tweet,
+        tipper: *ctx.accounts.tipper.key,
+        tweet_author: ctx.accounts.tweet.tweet_author,
+        mint: tip_ledger.mint,
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct TipTweetTokenContext<'info> {
+    #[account(
+        mut,
+        constraint = tipper.key() != tweet.tweet_author @ TwitterError::CannotTipSelf
+    )]
+    pub tipper: Signer<'info>,
+
+    #[account(has_one = tweet_author)]
+    pub tweet: Account<'info, Tweet>,
+
+    pub tweet_author: SystemAccount<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = tipper,
+        associated_token::token_program = token_program
+    )]
+    pub tipper_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = tipper,
+        associated_token::mint = mint,
+        associated_token::authority = tweet_author,
+        associated_token::token_program = token_program
+    )]
+    pub author_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = tipper,
+        space = TipLedger::INIT_SPACE + 8,
+        seeds = [
+            TIP_LEDGER_SEED.as_bytes(),
+            tweet.key().as_ref(),
+            mint.key().as_ref()
+        ],
+        bump
+    )]
+    pub tip_ledger: Account<'info, TipLedger>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
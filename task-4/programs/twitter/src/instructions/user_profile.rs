@@ -0,0 +1,222 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::events::{HandleClaimed, HandleReleased, ProfileCreated, ProfileUpdated};
+use crate::states::*;
+use crate::validation::{validate_handle, validate_profile_fields};
+
+pub fn create_profile(
+    ctx: Context<CreateProfileContext>,
+    handle: String,
+    display_name: String,
+    bio: String,
+    avatar_uri: String,
+) -> Result<()> {
+    validate_handle(&handle)?;
+    validate_profile_fields(&display_name, &bio, &avatar_uri)?;
+
+    // `init_if_needed` lets us report a taken handle with a dedicated error
+    // instead of the generic "already in use" from the system program.
+    let handle_account = &mut ctx.accounts.handle_account;
+    if handle_account.authority != Pubkey::default() {
+        return err!(TwitterError::HandleTaken);
+    }
+    handle_account.authority = *ctx.accounts.authority.key;
+    handle_account.bump = ctx.bumps.handle_account;
+
+    let user_profile = &mut ctx.accounts.user_profile;
+    user_profile.authority = *ctx.accounts.authority.key;
+    user_profile.handle = handle;
+    user_profile.display_name = display_name;
+    user_profile.bio = bio;
+    user_profile.avatar_uri = avatar_uri;
+    user_profile.follower_count = 0;
+    user_profile.following_count = 0;
+    user_profile.bump = ctx.bumps.user_profile;
+
+    emit!(ProfileCreated {
+        user_profile: user_profile.key(),
+        authority: user_profile.authority,
+        handle: user_profile.handle.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn update_profile(
+    ctx: Context<UpdateProfileContext>,
+    display_name: String,
+    bio: String,
+    avatar_uri: String,
+) -> Result<()> {
+    validate_profile_fields(&display_name, &bio, &avatar_uri)?;
+
+    let user_profile = &mut ctx.accounts.user_profile;
+    user_profile.display_name = display_name;
+    user_profile.bio = bio;
+    user_profile.avatar_uri = avatar_uri;
+
+    emit!(ProfileUpdated {
+        user_profile: user_profile.key(),
+        authority: user_profile.authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Closes the handle registry entry so someone else can claim the name. The
+/// profile keeps existing without a handle until `claim_handle` is called.
+pub fn release_handle(ctx: Context<ReleaseHandleContext>) -> Result<()> {
+    let user_profile = &mut ctx.accounts.user_profile;
+    let handle = std::mem::take(&mut user_profile.handle);
+
+    emit!(HandleReleased {
+        user_profile: user_profile.key(),
+        authority: user_profile.authority,
+        handle,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Registers a new handle for a profile whose previous handle was released.
+pub fn claim_handle(ctx: Context<ClaimHandleContext>, handle: String) -> Result<()> {
+    validate_handle(&handle)?;
+
+    let user_profile = &mut ctx.accounts.user_profile;
+    if !user_profile.handle.is_empty() {
+        return err!(TwitterError::HandleAlreadySet);
+    }
+
+    // Same as `create_profile`, a live registry entry means the handle is taken.
+    let handle_account = &mut ctx.accounts.handle_account;
+    if handle_account.authority != Pubkey::default() {
+        return err!(TwitterError::HandleTaken);
+    }
+    handle_account.authority = *ctx.accounts.authority.key;
+    handle_account.bump = ctx.bumps.handle_account;
+
+    user_profile.handle = handle;
+
+    emit!(HandleClaimed {
+        user_profile: user_profile.key(),
+        authority: user_profile.authority,
+        handle: user_profile.handle.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(handle: String)]
+pub struct CreateProfileContext<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = UserProfile::INIT_SPACE + 8,
+        seeds = [
+            USER_PROFILE_SEED.as_bytes(),
+            authority.key().as_ref()
+        ],
+        bump
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = Handle::INIT_SPACE + 8,
+        seeds = [
+            HANDLE_SEED.as_bytes(),
+            handle.as_bytes()
+        ],
+        bump
+    )]
+    pub handle_account: Account<'info, Handle>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateProfileContext<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority,
+        seeds = [
+            USER_PROFILE_SEED.as_bytes(),
+            authority.key().as_ref()
+        ],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+}
+
+#[derive(Accounts)]
+pub struct ReleaseHandleContext<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority,
+        seeds = [
+            USER_PROFILE_SEED.as_bytes(),
+            authority.key().as_ref()
+        ],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    #[account(
+        mut,
+        has_one = authority,
+        close = authority,
+        seeds = [
+            HANDLE_SEED.as_bytes(),
+            user_profile.handle.as_bytes()
+        ],
+        bump = handle_account.bump
+    )]
+    pub handle_account: Account<'info, Handle>,
+}
+
+#[derive(Accounts)]
+#[instruction(handle: String)]
+pub struct ClaimHandleContext<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority,
+        seeds = [
+            USER_PROFILE_SEED.as_bytes(),
+            authority.key().as_ref()
+        ],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = Handle::INIT_SPACE + 8,
+        seeds = [
+            HANDLE_SEED.as_bytes(),
+            handle.as_bytes()
+        ],
+        bump
+    )]
+    pub handle_account: Account<'info, Handle>,
+
+    pub system_program: Program<'info, System>,
+}
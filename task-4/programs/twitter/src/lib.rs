@@ -1,52 +1,163 @@
 #![allow(unexpected_cfgs)]
+// `#[program]` emits Anchor's on-chain IDL instructions at the crate root, and
+// they call the deprecated `AccountInfo::realloc`. The lint is turned back on
+// for every module of our own below.
+#![allow(deprecated)]
 
 //===============================================================================
-///
-/// TWITTER PROGRAM TASK
-/// 
-/// This is a decentralized Twitter-like program built on Solana that allows users to:
-/// - Create tweets with topics and content
-/// - Add reactions (likes/dislikes) to tweets
-/// - Comment on tweets
-/// - Remove their own reactions and comments
-/// 
-/// INSTRUCTIONS:
-/// Complete the implementation of all instructions by filling in the TODOs.
-/// Each instruction file contains specific requirements and constraints to implement.
-/// 
-/// GENERAL HINTS:
-/// - Use constants from states.rs (TOPIC_LENGTH, CONTENT_LENGTH, etc.)
-/// - Use available errors from errors.rs for validation
-/// - Follow the PDA seed patterns shown below for account derivation
-/// - Validate input lengths before processing
-/// - Set all required account fields including bump values
-/// - Imports
-/// 
-/// SEEDS:
-/// - Tweet: [topic.as_bytes(), TWEET_SEED.as_bytes(), tweet_authority.key().as_ref()]
-/// - TweetReaction: [TWEET_REACTION_SEED.as_bytes(), reaction_author.key().as_ref(), tweet.key().as_ref()]
-/// - Comment: [COMMENT_SEED.as_bytes(), comment_author.key().as_ref(), {hash(comment.content.as_bytes()).to_bytes().as_ref()}, comment.parent_tweet.key().as_ref()]
-/// 
-/// GOOD LUCK!
-/// 
-///===============================================================================
+//
+// TWITTER PROGRAM TASK
+// 
+// This is a decentralized Twitter-like program built on Solana that allows users to:
+// - Create tweets with topics and content, tagging #topics and @mentioning users
+// - Migrate tweets created under the old topic seeds to sequence-numbered addresses
+// - Edit and delete their own tweets
+// - Retweet or quote other tweets, and reclaim retweets of deleted tweets
+// - Tip tweet authors in SOL or SPL tokens
+// - Bookmark tweets to read later
+// - Attach polls to their tweets and vote on other users' polls
+// - Add reactions (likes, dislikes and emoji reactions) to tweets and change them in place
+// - Comment on tweets and reply to comments
+// - Remove their own reactions and comments, or reclaim them once the tweet is deleted
+// - Create a profile with a unique handle and follow other users
+// - Block users from reacting to or commenting on their tweets
+// - Moderators appointed by the program admin can hide tweets and comments, and unhide them
+// - Report tweets, which go under review once enough reports come in; moderators
+//   resolve a review by hiding the tweet or dismissing the review
+// 
+// INSTRUCTIONS:
+// Complete the implementation of all instructions by filling in the TODOs.
+// Each instruction file contains specific requirements and constraints to implement.
+// 
+// GENERAL HINTS:
+// - Use constants from states.rs (TOPIC_LENGTH, CONTENT_LENGTH, etc.)
+// - Use available errors from errors.rs for validation
+// - Follow the PDA seed patterns shown below for account derivation
+// - Validate user supplied text with the helpers in validation.rs
+// - Set all required account fields including bump values
+// - Imports
+// 
+// SEEDS:
+// - ProgramConfig: [CONFIG_SEED.as_bytes()]
+// - Report: [REPORT_SEED.as_bytes(), tweet.key().as_ref(), reporter.key().as_ref()]
+// - Tweet: [TWEET_SEED.as_bytes(), tweet_authority.key().as_ref(), author_state.tweet_count.to_le_bytes().as_ref()]
+// - Tweet (legacy, read only by migrate_legacy_tweet): [topic.as_bytes(), TWEET_SEED.as_bytes(), tweet_authority.key().as_ref()]
+// - TopicIndex: [TOPIC_INDEX_SEED.as_bytes(), hash(topic.as_bytes()).as_ref()]
+// - TipLedger: [TIP_LEDGER_SEED.as_bytes(), tweet.key().as_ref(), mint.key().as_ref()]
+// - AuthorState: [AUTHOR_STATE_SEED.as_bytes(), tweet_authority.key().as_ref()]
+// - Bookmark: [BOOKMARK_SEED.as_bytes(), user.key().as_ref(), tweet.key().as_ref()]
+// - Poll: [POLL_SEED.as_bytes(), tweet.key().as_ref()]
+// - PollVote: [POLL_VOTE_SEED.as_bytes(), poll.key().as_ref(), voter.key().as_ref()]
+// - TweetReaction: [TWEET_REACTION_SEED.as_bytes(), reaction_author.key().as_ref(), tweet.key().as_ref()]
+// - Comment: [COMMENT_SEED.as_bytes(), parent_tweet.key().as_ref(), parent_tweet.next_comment_id.to_le_bytes().as_ref()]
+// - UserProfile: [USER_PROFILE_SEED.as_bytes(), authority.key().as_ref()]
+// - Handle: [HANDLE_SEED.as_bytes(), handle.as_bytes()]
+// - Retweet: [RETWEET_SEED.as_bytes(), retweeter.key().as_ref(), original_tweet.key().as_ref()]
+// - Follow: [FOLLOW_SEED.as_bytes(), follower.key().as_ref(), followee.key().as_ref()]
+// - Block: [BLOCK_SEED.as_bytes(), blocker.key().as_ref(), blocked.key().as_ref()]
+// 
+// GOOD LUCK!
+// 
+//===============================================================================
 
 use crate::instructions::*;
 use anchor_lang::prelude::*;
 
+#[warn(deprecated)]
+pub mod content;
+#[warn(deprecated)]
 pub mod errors;
+#[warn(deprecated)]
+pub mod events;
+#[warn(deprecated)]
 pub mod instructions;
+#[warn(deprecated)]
 pub mod states;
+#[warn(deprecated)]
+pub mod validation;
 
 declare_id!("F6NKeaoPbchYnbcJZ5YSAqfMcHuP7GLExTuDK3qmgtgW");
 
+#[warn(deprecated)]
 #[program]
 pub mod twitter {
 
     use super::*;
 
-    pub fn initialize(ctx: Context<InitializeTweet>, topic: String, content: String) -> Result<()> {
-        initialize_tweet(ctx, topic, content)
+    pub fn initialize_config(ctx: Context<InitializeConfigContext>, report_threshold: u64) -> Result<()> {
+        instructions::initialize_config(ctx, report_threshold)
+    }
+    pub fn set_report_threshold(ctx: Context<UpdateConfigContext>, report_threshold: u64) -> Result<()> {
+        instructions::set_report_threshold(ctx, report_threshold)
+    }
+    pub fn set_rate_limit(ctx: Context<UpdateConfigContext>, window_slots: u64, max_actions: u64) -> Result<()> {
+        instructions::set_rate_limit(ctx, window_slots, max_actions)
+    }
+    pub fn add_moderator(ctx: Context<UpdateConfigContext>, moderator: Pubkey) -> Result<()> {
+        instructions::add_moderator(ctx, moderator)
+    }
+    pub fn remove_moderator(ctx: Context<UpdateConfigContext>, moderator: Pubkey) -> Result<()> {
+        instructions::remove_moderator(ctx, moderator)
+    }
+    pub fn report_tweet(ctx: Context<ReportTweetContext>, reason: states::ModerationReason) -> Result<()> {
+        instructions::report_tweet(ctx, reason)
+    }
+    pub fn hide_tweet(ctx: Context<HideTweetContext>, reason: states::ModerationReason) -> Result<()> {
+        instructions::hide_tweet(ctx, reason)
+    }
+    pub fn dismiss_review(ctx: Context<HideTweetContext>) -> Result<()> {
+        instructions::dismiss_review(ctx)
+    }
+    pub fn hide_comment(ctx: Context<HideCommentContext>, reason: states::ModerationReason) -> Result<()> {
+        instructions::hide_comment(ctx, reason)
+    }
+    pub fn unhide_tweet(ctx: Context<HideTweetContext>) -> Result<()> {
+        instructions::unhide_tweet(ctx)
+    }
+    pub fn unhide_comment(ctx: Context<HideCommentContext>) -> Result<()> {
+        instructions::unhide_comment(ctx)
+    }
+    pub fn initialize(ctx: Context<CreateTweetContext>, topic: String, content: String) -> Result<()> {
+        instructions::create_tweet(ctx, topic, content)
+    }
+    pub fn create_tweet(ctx: Context<CreateTweetContext>, topic: String, content: String) -> Result<()> {
+        instructions::create_tweet(ctx, topic, content)
+    }
+    pub fn migrate_legacy_tweet(ctx: Context<MigrateLegacyTweetContext>, topic: String) -> Result<()> {
+        instructions::migrate_legacy_tweet(ctx, topic)
+    }
+    pub fn edit_tweet(ctx: Context<EditTweetContext>, content: String) -> Result<()> {
+        instructions::edit_tweet(ctx, content)
+    }
+    pub fn delete_tweet(ctx: Context<DeleteTweetContext>) -> Result<()> {
+        instructions::delete_tweet(ctx)
+    }
+    pub fn retweet(ctx: Context<RetweetContext>) -> Result<()> {
+        instructions::retweet(ctx)
+    }
+    pub fn undo_retweet(ctx: Context<UndoRetweetContext>) -> Result<()> {
+        instructions::undo_retweet(ctx)
+    }
+    pub fn quote_tweet(ctx: Context<QuoteTweetContext>, topic: String, content: String) -> Result<()> {
+        instructions::quote_tweet(ctx, topic, content)
+    }
+    pub fn tip_tweet(ctx: Context<TipTweetContext>, amount: u64) -> Result<()> {
+        instructions::tip_tweet(ctx, amount)
+    }
+    pub fn tip_tweet_token(ctx: Context<TipTweetTokenContext>, amount: u64) -> Result<()> {
+        instructions::tip_tweet_token(ctx, amount)
+    }
+    pub fn bookmark_tweet(ctx: Context<BookmarkTweetContext>) -> Result<()> {
+        instructions::bookmark_tweet(ctx)
+    }
+    pub fn remove_bookmark(ctx: Context<RemoveBookmarkContext>) -> Result<()> {
+        instructions::remove_bookmark(ctx)
+    }
+    pub fn create_poll(ctx: Context<CreatePollContext>, options: Vec<String>, end_timestamp: i64) -> Result<()> {
+        instructions::create_poll(ctx, options, end_timestamp)
+    }
+    pub fn vote_poll(ctx: Context<VotePollContext>, option_index: u8) -> Result<()> {
+        instructions::vote_poll(ctx, option_index)
     }
     pub fn like_tweet(ctx: Context<AddReactionContext>) -> Result<()> {
         add_reaction(ctx, states::ReactionType::Like)
@@ -54,16 +165,78 @@ pub mod twitter {
     pub fn dislike_tweet(ctx: Context<AddReactionContext>) -> Result<()> {
         add_reaction(ctx, states::ReactionType::Dislike)
     }
+    pub fn react_tweet(ctx: Context<AddReactionContext>, reaction: states::ReactionType) -> Result<()> {
+        add_reaction(ctx, reaction)
+    }
     pub fn reaction_remove(ctx: Context<RemoveReactionContext>) -> Result<()> {
         remove_reaction(ctx)
     }
+    pub fn change_reaction(ctx: Context<ChangeReactionContext>, reaction: states::ReactionType) -> Result<()> {
+        instructions::change_reaction(ctx, reaction)
+    }
     pub fn comment_tweet(ctx: Context<AddCommentContext>, comment_content: String) -> Result<()> {
         add_comment(ctx, comment_content)
     }
-    // pub fn comment_remove(ctx: Context<RemoveCommentContext>) -> Result<()> {
-    //     remove_comment(ctx)
-    // }
-    pub fn comment_remove(ctx: Context<RemoveCommentContext>, comment_content: String) -> Result<()> {
-        remove_comment(ctx, comment_content)
+    pub fn reply_to_comment(ctx: Context<ReplyToCommentContext>, comment_content: String) -> Result<()> {
+        instructions::reply_to_comment(ctx, comment_content)
+    }
+    pub fn comment_remove(ctx: Context<RemoveCommentContext>) -> Result<()> {
+        remove_comment(ctx)
+    }
+    pub fn reclaim_reaction(ctx: Context<ReclaimReactionContext>) -> Result<()> {
+        instructions::reclaim_reaction(ctx)
+    }
+    pub fn reclaim_comment(ctx: Context<ReclaimCommentContext>) -> Result<()> {
+        instructions::reclaim_comment(ctx)
+    }
+    pub fn reclaim_retweet(ctx: Context<ReclaimRetweetContext>) -> Result<()> {
+        instructions::reclaim_retweet(ctx)
+    }
+    pub fn reclaim_poll(ctx: Context<ReclaimPollContext>) -> Result<()> {
+        instructions::reclaim_poll(ctx)
+    }
+    pub fn reclaim_poll_vote(ctx: Context<ReclaimPollVoteContext>) -> Result<()> {
+        instructions::reclaim_poll_vote(ctx)
+    }
+    pub fn reclaim_report(ctx: Context<ReclaimReportContext>) -> Result<()> {
+        instructions::reclaim_report(ctx)
+    }
+    pub fn reclaim_tip_ledger(ctx: Context<ReclaimTipLedgerContext>) -> Result<()> {
+        instructions::reclaim_tip_ledger(ctx)
+    }
+    pub fn create_profile(
+        ctx: Context<CreateProfileContext>,
+        handle: String,
+        display_name: String,
+        bio: String,
+        avatar_uri: String,
+    ) -> Result<()> {
+        instructions::create_profile(ctx, handle, display_name, bio, avatar_uri)
+    }
+    pub fn update_profile(
+        ctx: Context<UpdateProfileContext>,
+        display_name: String,
+        bio: String,
+        avatar_uri: String,
+    ) -> Result<()> {
+        instructions::update_profile(ctx, display_name, bio, avatar_uri)
+    }
+    pub fn release_handle(ctx: Context<ReleaseHandleContext>) -> Result<()> {
+        instructions::release_handle(ctx)
+    }
+    pub fn claim_handle(ctx: Context<ClaimHandleContext>, handle: String) -> Result<()> {
+        instructions::claim_handle(ctx, handle)
+    }
+    pub fn follow(ctx: Context<FollowContext>) -> Result<()> {
+        instructions::follow(ctx)
+    }
+    pub fn unfollow(ctx: Context<UnfollowContext>) -> Result<()> {
+        instructions::unfollow(ctx)
+    }
+    pub fn block_user(ctx: Context<BlockUserContext>) -> Result<()> {
+        instructions::block_user(ctx)
+    }
+    pub fn unblock_user(ctx: Context<UnblockUserContext>) -> Result<()> {
+        instructions::unblock_user(ctx)
     }
 }
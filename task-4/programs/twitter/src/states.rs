@@ -1,38 +1,361 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+
+use crate::content::extract_hashtags;
+use crate::errors::TwitterError;
 
 pub const TOPIC_LENGTH: usize = 32;
 pub const CONTENT_LENGTH: usize = 500;
 pub const COMMENT_LENGTH: usize = 500;
+pub const REACTION_TYPE_COUNT: usize = 7;
+pub const HANDLE_LENGTH: usize = 15;
+pub const DISPLAY_NAME_LENGTH: usize = 50;
+pub const BIO_LENGTH: usize = 160;
+pub const AVATAR_URI_LENGTH: usize = 200;
+pub const MAX_REPLY_DEPTH: u8 = 5;
+pub const TOPIC_INDEX_RECENT_TWEETS: usize = 10;
+pub const MAX_HASHTAGS: usize = 5;
+pub const HASHTAG_LENGTH: usize = 32;
+pub const MAX_MENTIONS: usize = 5;
+pub const MIN_POLL_OPTIONS: usize = 2;
+pub const MAX_POLL_OPTIONS: usize = 4;
+pub const POLL_OPTION_LENGTH: usize = 50;
+pub const MAX_MODERATORS: usize = 10;
 
 pub const TWEET_SEED: &str = "TWEET_SEED";
 pub const TWEET_REACTION_SEED: &str = "TWEET_REACTION_SEED";
 pub const COMMENT_SEED: &str = "COMMENT_SEED";
+pub const USER_PROFILE_SEED: &str = "USER_PROFILE_SEED";
+pub const HANDLE_SEED: &str = "HANDLE_SEED";
+pub const FOLLOW_SEED: &str = "FOLLOW_SEED";
+pub const RETWEET_SEED: &str = "RETWEET_SEED";
+pub const AUTHOR_STATE_SEED: &str = "AUTHOR_STATE_SEED";
+pub const TOPIC_INDEX_SEED: &str = "TOPIC_INDEX_SEED";
+pub const TIP_LEDGER_SEED: &str = "TIP_LEDGER_SEED";
+pub const BOOKMARK_SEED: &str = "BOOKMARK_SEED";
+pub const POLL_SEED: &str = "POLL_SEED";
+pub const POLL_VOTE_SEED: &str = "POLL_VOTE_SEED";
+pub const CONFIG_SEED: &str = "CONFIG_SEED";
+pub const REPORT_SEED: &str = "REPORT_SEED";
+pub const BLOCK_SEED: &str = "BLOCK_SEED";
 
-#[derive(AnchorDeserialize, AnchorSerialize, Clone, InitSpace)]
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, PartialEq, Eq, InitSpace)]
 pub enum ReactionType {
     Like,
     Dislike,
+    Love,
+    Laugh,
+    Celebrate,
+    Sad,
+    Angry,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum ModerationReason {
+    Spam,
+    Harassment,
+    HateSpeech,
+    Violence,
+    IllegalContent,
+    Misinformation,
+    Other,
 }
 
 #[account]
 #[derive(InitSpace)]
 pub struct Tweet {
     pub tweet_author: Pubkey,
+    /// Sequence number from the author's `AuthorState`, used in the tweet's
+    /// PDA seeds.
+    pub tweet_id: u64,
     #[max_len(TOPIC_LENGTH)]
     pub topic: String,
     #[max_len(CONTENT_LENGTH)]
     pub content: String,
+    #[max_len(MAX_HASHTAGS, HASHTAG_LENGTH)]
+    pub hashtags: Vec<String>,
+    pub reactions: [u64; REACTION_TYPE_COUNT],
+    pub comment_count: u64,
+    pub next_comment_id: u64,
+    pub retweet_count: u64,
+    pub quoted_tweet: Option<Pubkey>,
+    /// Cumulative lamports tipped to the author through this tweet.
+    pub tips_received: u64,
+    pub edit_count: u64,
+    pub last_edited_at: i64,
+    /// Set by a moderator to take the tweet down without closing it.
+    pub hidden_reason: Option<ModerationReason>,
+    pub report_count: u64,
+    /// Set once `report_count` reaches the configured threshold, cleared when
+    /// a moderator hides the tweet.
+    pub under_review: bool,
+    pub created_at: i64,
+    pub created_slot: u64,
+    pub bump: u8,
+}
+
+impl Tweet {
+    /// Sets every field of a freshly created tweet, shared by all tweet
+    /// creation paths so counters always start from zero and the creation
+    /// time is always recorded.
+    pub fn initialize(
+        &mut self,
+        tweet_author: Pubkey,
+        tweet_id: u64,
+        topic: String,
+        content: String,
+        quoted_tweet: Option<Pubkey>,
+        bump: u8,
+    ) -> Result<()> {
+        self.tweet_author = tweet_author;
+        self.tweet_id = tweet_id;
+        self.topic = topic;
+        self.set_content(content)?;
+        self.reactions = [0; REACTION_TYPE_COUNT];
+        self.comment_count = 0;
+        self.next_comment_id = 0;
+        self.retweet_count = 0;
+        self.quoted_tweet = quoted_tweet;
+        self.tips_received = 0;
+        self.edit_count = 0;
+        self.last_edited_at = 0;
+        self.hidden_reason = None;
+        self.report_count = 0;
+        self.under_review = false;
+        let clock = Clock::get()?;
+        self.created_at = clock.unix_timestamp;
+        self.created_slot = clock.slot;
+        self.bump = bump;
+        Ok(())
+    }
+
+    /// Account size (without discriminator) for a tweet holding exactly this
+    /// topic and content. `INIT_SPACE` reserves room for the longest topic,
+    /// content and hashtags, only the bytes actually stored are paid for.
+    pub fn space(topic: &str, content: &str) -> usize {
+        let hashtags = extract_hashtags(content)
+            .map(|hashtags| hashtags.iter().map(|hashtag| 4 + hashtag.len()).sum())
+            .unwrap_or(0);
+        Self::INIT_SPACE - TOPIC_LENGTH - CONTENT_LENGTH - MAX_HASHTAGS * (4 + HASHTAG_LENGTH)
+            + topic.len()
+            + content.len()
+            + hashtags
+    }
+
+    /// Replaces the content and re-derives the hashtags stored alongside it.
+    pub fn set_content(&mut self, content: String) -> Result<()> {
+        self.hashtags = extract_hashtags(&content)?;
+        self.content = content;
+        Ok(())
+    }
+
+    pub fn increment_reaction(&mut self, reaction: ReactionType) -> Result<()> {
+        let counter = &mut self.reactions[reaction as usize];
+        *counter = counter
+            .checked_add(1)
+            .ok_or(TwitterError::MaxReactionsReached)?;
+        Ok(())
+    }
+
+    pub fn decrement_reaction(&mut self, reaction: ReactionType) -> Result<()> {
+        let counter = &mut self.reactions[reaction as usize];
+        *counter = counter
+            .checked_sub(1)
+            .ok_or(TwitterError::MinReactionsReached)?;
+        Ok(())
+    }
+
+    /// Hands out the next comment sequence number and bumps the comment counter.
+    pub fn register_comment(&mut self) -> Result<u64> {
+        let comment_id = self.next_comment_id;
+        self.next_comment_id = comment_id
+            .checked_add(1)
+            .ok_or(TwitterError::MaxCommentsReached)?;
+        self.comment_count = self
+            .comment_count
+            .checked_add(1)
+            .ok_or(TwitterError::MaxCommentsReached)?;
+        Ok(comment_id)
+    }
+}
+
+/// Layout of the tweets `initialize` created before tweets were sequence
+/// numbered, seeded by `[topic, TWEET_SEED, author]`. They share `Tweet`'s
+/// discriminator, so they fail to deserialize as a `Tweet` until
+/// `migrate_legacy_tweet` moves them to a sequence-numbered address.
+#[derive(AnchorDeserialize)]
+pub struct LegacyTweet {
+    pub tweet_author: Pubkey,
+    pub topic: String,
+    pub content: String,
     pub likes: u64,
     pub dislikes: u64,
     pub bump: u8,
 }
 
+impl LegacyTweet {
+    pub fn try_from_account(account: &AccountInfo) -> Result<Self> {
+        require_keys_eq!(*account.owner, crate::ID, ErrorCode::AccountOwnedByWrongProgram);
+        let data = account.try_borrow_data()?;
+        require!(
+            data.starts_with(Tweet::DISCRIMINATOR),
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        Ok(Self::deserialize(&mut &data[Tweet::DISCRIMINATOR.len()..])?)
+    }
+
+    /// Size (without discriminator) of the `Tweet` this one migrates to.
+    pub fn migrated_space(&self) -> usize {
+        Tweet::space(&self.topic, &self.content)
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct AuthorState {
+    pub author: Pubkey,
+    pub tweet_count: u64,
+    pub bookmark_count: u64,
+    /// First slot of the current rate limit window.
+    pub window_start_slot: u64,
+    /// Tweets and comments posted since `window_start_slot`.
+    pub actions_in_window: u64,
+    pub bump: u8,
+}
+
+impl AuthorState {
+    /// Fills in the owner of an `init_if_needed` author state on first use.
+    pub fn initialize_if_new(&mut self, author: Pubkey, bump: u8) {
+        if self.author == Pubkey::default() {
+            self.author = author;
+            self.bump = bump;
+        }
+    }
+
+    /// Counts a tweet or comment against the configured rate limit, starting
+    /// a new window once the current one has elapsed.
+    pub fn record_action(&mut self, config: &ProgramConfig, slot: u64) -> Result<()> {
+        if config.rate_limit_window_slots == 0 {
+            return Ok(());
+        }
+        if slot >= self.window_start_slot.saturating_add(config.rate_limit_window_slots) {
+            self.window_start_slot = slot;
+            self.actions_in_window = 0;
+        }
+        if self.actions_in_window >= config.rate_limit_max_actions {
+            return err!(TwitterError::RateLimited);
+        }
+        self.actions_in_window += 1;
+        Ok(())
+    }
+
+    /// Hands out the next tweet sequence number.
+    pub fn register_tweet(&mut self) -> Result<u64> {
+        let tweet_id = self.tweet_count;
+        self.tweet_count = tweet_id
+            .checked_add(1)
+            .ok_or(TwitterError::MaxTweetsReached)?;
+        Ok(tweet_id)
+    }
+}
+
+/// Per-topic feed: the number of live tweets and a ring buffer of the most
+/// recently created ones. Deleted tweets leave a `Pubkey::default()` hole.
+#[account]
+#[derive(InitSpace)]
+pub struct TopicIndex {
+    #[max_len(TOPIC_LENGTH)]
+    pub topic: String,
+    pub tweet_count: u64,
+    pub recent_tweets: [Pubkey; TOPIC_INDEX_RECENT_TWEETS],
+    pub next_slot: u8,
+    pub bump: u8,
+}
+
+impl TopicIndex {
+    /// Topic indexes are seeded by the SHA-256 of the topic, which always fits
+    /// in a seed, so an over-long topic reaches `validate_topic` instead of
+    /// failing the PDA derivation.
+    pub fn topic_seed(topic: &str) -> [u8; 32] {
+        hash(topic.as_bytes()).to_bytes()
+    }
+
+    /// Fills in the topic of an `init_if_needed` index on first use.
+    pub fn initialize_if_new(&mut self, topic: &str, bump: u8) {
+        // `bump` is only zero on an index that was just created.
+        if self.bump == 0 {
+            self.topic = topic.to_string();
+            self.bump = bump;
+        }
+    }
+
+    pub fn push_tweet(&mut self, tweet: Pubkey) -> Result<()> {
+        self.tweet_count = self
+            .tweet_count
+            .checked_add(1)
+            .ok_or(TwitterError::MaxTweetsReached)?;
+        self.recent_tweets[self.next_slot as usize] = tweet;
+        self.next_slot = ((self.next_slot as usize + 1) % TOPIC_INDEX_RECENT_TWEETS) as u8;
+        Ok(())
+    }
+
+    pub fn remove_tweet(&mut self, tweet: Pubkey) -> Result<()> {
+        self.tweet_count = self
+            .tweet_count
+            .checked_sub(1)
+            .ok_or(TwitterError::MinTweetsReached)?;
+        if let Some(slot) = self.recent_tweets.iter_mut().find(|key| **key == tweet) {
+            *slot = Pubkey::default();
+        }
+        Ok(())
+    }
+}
+
+/// Global program settings, created once by the program's upgrade authority.
+#[account]
+#[derive(InitSpace)]
+pub struct ProgramConfig {
+    pub admin: Pubkey,
+    #[max_len(MAX_MODERATORS)]
+    pub moderators: Vec<Pubkey>,
+    /// Number of reports that puts a tweet under review.
+    pub report_threshold: u64,
+    /// Length of a rate limit window, zero disables rate limiting.
+    pub rate_limit_window_slots: u64,
+    /// Tweets and comments an author may post per window.
+    pub rate_limit_max_actions: u64,
+    pub bump: u8,
+}
+
+impl ProgramConfig {
+    /// The admin can always moderate, even without being in `moderators`.
+    pub fn is_moderator(&self, key: &Pubkey) -> bool {
+        self.admin == *key || self.moderators.contains(key)
+    }
+}
+
+/// Running total of token tips received by a tweet in a single mint.
+#[account]
+#[derive(InitSpace)]
+pub struct TipLedger {
+    pub tweet: Pubkey,
+    pub mint: Pubkey,
+    /// First tipper, who paid the ledger's rent and gets it back through
+    /// `reclaim_tip_ledger` once the tweet is deleted.
+    pub payer: Pubkey,
+    pub total_amount: u64,
+    pub tip_count: u64,
+    pub bump: u8,
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct Reaction {
     pub reaction_author: Pubkey,
     pub parent_tweet: Pubkey,
     pub reaction: ReactionType,
+    pub created_at: i64,
+    pub created_slot: u64,
     pub bump: u8,
 }
 
@@ -41,7 +364,116 @@ pub struct Reaction {
 pub struct Comment {
     pub comment_author: Pubkey,
     pub parent_tweet: Pubkey,
+    pub comment_id: u64,
+    pub parent_comment: Option<Pubkey>,
+    pub reply_count: u64,
+    pub depth: u8,
     #[max_len(COMMENT_LENGTH)]
     pub content: String,
+    /// SHA-256 of `content`. The PDA is seeded by `comment_id` instead, so
+    /// removal never needs the content or its hash.
+    pub content_hash: [u8; 32],
+    /// Set by a moderator to take the comment down without closing it.
+    pub hidden_reason: Option<ModerationReason>,
+    pub created_at: i64,
+    pub created_slot: u64,
+    pub bump: u8,
+}
+
+impl Comment {
+    /// Account size (without discriminator) for a comment holding exactly
+    /// this content.
+    pub fn space(content: &str) -> usize {
+        Self::INIT_SPACE - COMMENT_LENGTH + content.len()
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct UserProfile {
+    pub authority: Pubkey,
+    #[max_len(HANDLE_LENGTH)]
+    pub handle: String,
+    #[max_len(DISPLAY_NAME_LENGTH)]
+    pub display_name: String,
+    #[max_len(BIO_LENGTH)]
+    pub bio: String,
+    #[max_len(AVATAR_URI_LENGTH)]
+    pub avatar_uri: String,
+    pub follower_count: u64,
+    pub following_count: u64,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Handle {
+    pub authority: Pubkey,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Follow {
+    pub follower: Pubkey,
+    pub followee: Pubkey,
+    pub bump: u8,
+}
+
+/// A tweet saved by `user`. Only the user can create or close it.
+#[account]
+#[derive(InitSpace)]
+pub struct Bookmark {
+    pub user: Pubkey,
+    pub tweet: Pubkey,
+    pub bump: u8,
+}
+
+/// Poll attached to a tweet by its author. Votes are accepted until
+/// `end_timestamp`. The author is kept so the rent can be reclaimed once the
+/// tweet is deleted.
+#[account]
+#[derive(InitSpace)]
+pub struct Poll {
+    pub tweet: Pubkey,
+    pub tweet_author: Pubkey,
+    #[max_len(MAX_POLL_OPTIONS, POLL_OPTION_LENGTH)]
+    pub options: Vec<String>,
+    pub tallies: [u64; MAX_POLL_OPTIONS],
+    pub end_timestamp: i64,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct PollVote {
+    pub poll: Pubkey,
+    pub voter: Pubkey,
+    pub option_index: u8,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Report {
+    pub reporter: Pubkey,
+    pub tweet: Pubkey,
+    pub reason: ModerationReason,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Block {
+    pub blocker: Pubkey,
+    pub blocked: Pubkey,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Retweet {
+    pub retweeter: Pubkey,
+    pub original_tweet: Pubkey,
     pub bump: u8,
 }
@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::states::*;
+
+// Every limit is a byte limit, since that is what the accounts reserve: a
+// multi-byte UTF-8 character counts once per byte it takes up.
+
+/// Topics are a single line: control characters, including newlines, are
+/// rejected.
+pub fn validate_topic(topic: &str) -> Result<()> {
+    validate_text(
+        topic,
+        TOPIC_LENGTH,
+        false,
+        TwitterError::TopicEmpty,
+        TwitterError::TopicTooLong,
+        TwitterError::TopicInvalidCharacters,
+    )
+}
+
+/// Tweet content may span several lines, any other control character is
+/// rejected.
+pub fn validate_content(content: &str) -> Result<()> {
+    validate_text(
+        content,
+        CONTENT_LENGTH,
+        true,
+        TwitterError::ContentEmpty,
+        TwitterError::ContentTooLong,
+        TwitterError::ContentInvalidCharacters,
+    )
+}
+
+/// Comments and replies follow the same rules as tweet content, with their
+/// own limit and errors.
+pub fn validate_comment(comment: &str) -> Result<()> {
+    validate_text(
+        comment,
+        COMMENT_LENGTH,
+        true,
+        TwitterError::CommentEmpty,
+        TwitterError::CommentTooLong,
+        TwitterError::CommentInvalidCharacters,
+    )
+}
+
+/// Handles are stored lowercase so the registry PDA is case-insensitive.
+pub fn validate_handle(handle: &str) -> Result<()> {
+    if handle.is_empty()
+        || handle.len() > HANDLE_LENGTH
+        || !handle
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'_')
+    {
+        return err!(TwitterError::InvalidHandle);
+    }
+    Ok(())
+}
+
+/// Profile fields are optional, so empty values are accepted. The bio may
+/// span several lines, the display name and avatar URI may not.
+pub fn validate_profile_fields(display_name: &str, bio: &str, avatar_uri: &str) -> Result<()> {
+    validate_optional_text(
+        display_name,
+        DISPLAY_NAME_LENGTH,
+        false,
+        TwitterError::DisplayNameTooLong,
+        TwitterError::DisplayNameInvalidCharacters,
+    )?;
+    validate_optional_text(
+        bio,
+        BIO_LENGTH,
+        true,
+        TwitterError::BioTooLong,
+        TwitterError::BioInvalidCharacters,
+    )?;
+    validate_optional_text(
+        avatar_uri,
+        AVATAR_URI_LENGTH,
+        false,
+        TwitterError::AvatarUriTooLong,
+        TwitterError::AvatarUriInvalidCharacters,
+    )
+}
+
+/// Every poll option is a non-empty single line label.
+pub fn validate_poll_options(options: &[String]) -> Result<()> {
+    if !(MIN_POLL_OPTIONS..=MAX_POLL_OPTIONS).contains(&options.len()) {
+        return err!(TwitterError::InvalidPollOptionCount);
+    }
+    for option in options {
+        validate_text(
+            option,
+            POLL_OPTION_LENGTH,
+            false,
+            TwitterError::PollOptionEmpty,
+            TwitterError::PollOptionTooLong,
+            TwitterError::PollOptionInvalidCharacters,
+        )?;
+    }
+    Ok(())
+}
+
+fn validate_text(
+    text: &str,
+    max_len: usize,
+    allow_newlines: bool,
+    empty: TwitterError,
+    too_long: TwitterError,
+    invalid_characters: TwitterError,
+) -> Result<()> {
+    if text.is_empty() {
+        return Err(empty.into());
+    }
+    validate_optional_text(text, max_len, allow_newlines, too_long, invalid_characters)
+}
+
+fn validate_optional_text(
+    text: &str,
+    max_len: usize,
+    allow_newlines: bool,
+    too_long: TwitterError,
+    invalid_characters: TwitterError,
+) -> Result<()> {
+    if text.len() > max_len {
+        return Err(too_long.into());
+    }
+    if text
+        .chars()
+        .any(|c| c.is_control() && !(allow_newlines && c == '\n'))
+    {
+        return Err(invalid_characters.into());
+    }
+    Ok(())
+}
@@ -9,6 +9,32 @@ import crypto from "crypto";
 const TWEET_SEED = "TWEET_SEED";
 const TWEET_REACTION = "TWEET_REACTION_SEED";
 const COMMENT_SEED = "COMMENT_SEED";
+const USER_PROFILE_SEED = "USER_PROFILE_SEED";
+const HANDLE_SEED = "HANDLE_SEED";
+const FOLLOW_SEED = "FOLLOW_SEED";
+const RETWEET_SEED = "RETWEET_SEED";
+const AUTHOR_STATE_SEED = "AUTHOR_STATE_SEED";
+const TOPIC_INDEX_SEED = "TOPIC_INDEX_SEED";
+const TIP_LEDGER_SEED = "TIP_LEDGER_SEED";
+const BOOKMARK_SEED = "BOOKMARK_SEED";
+const POLL_SEED = "POLL_SEED";
+const POLL_VOTE_SEED = "POLL_VOTE_SEED";
+const CONFIG_SEED = "CONFIG_SEED";
+const REPORT_SEED = "REPORT_SEED";
+const BLOCK_SEED = "BLOCK_SEED";
+
+const TOKEN_PROGRAM_ID = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+const TOKEN_2022_PROGRAM_ID = new PublicKey("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+const ASSOCIATED_TOKEN_PROGRAM_ID = new PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+const BPF_LOADER_UPGRADEABLE_PROGRAM_ID = new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111");
+
+// Indexes into `Tweet.reactions`, matching the order of `ReactionType`
+const REACTION_LIKE = 0;
+const REACTION_DISLIKE = 1;
+const REACTION_LOVE = 2;
+
+// Matches `MAX_REPLY_DEPTH` in states.rs
+const MAX_REPLY_DEPTH = 5;
 
 describe("twitter", () => {
   const provider = anchor.AnchorProvider.env();
@@ -19,6 +45,7 @@ describe("twitter", () => {
   const bob = anchor.web3.Keypair.generate();
   const alice = anchor.web3.Keypair.generate();
   const charlie = anchor.web3.Keypair.generate();
+  const dave = anchor.web3.Keypair.generate();
 
   const topic_bob1 = "Hello There";
   const content_bob1 = "This is my first tweet on this app, I like it here!"
@@ -39,6 +66,8 @@ describe("twitter", () => {
 
   const comment_alice2 = "I dont`t like you Bob. It is enough if I say it once"
 
+  const report_threshold = 2;
+
   const topic_edge_case = "A".repeat(32);
   const content_edge_case = "B".repeat(500);
   const empty_topic = "";
@@ -48,18 +77,92 @@ describe("twitter", () => {
   const unicode_topic = "🚀 Crypto";
   const unicode_content = "Testing with emojis 🎉✨🔥";
 
+  describe("Program Config", async () => {
+    it("Should fail when the config is initialized by someone other than the upgrade authority", async () => {
+      const [config_pkey, config_bump] = getConfigAddress(program.programId);
+      await airdrop(provider.connection, alice.publicKey);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.initializeConfig(new anchor.BN(report_threshold)).accounts(
+          {
+            authority: alice.publicKey,
+            config: config_pkey,
+            program: program.programId,
+            programData: getProgramDataAddress(program.programId),
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "NotUpgradeAuthority", "Expected 'NotUpgradeAuthority' error for a non-authority signer");
+      }
+      assert.strictEqual(should_fail, "Failed", "Only the upgrade authority should be able to initialize the config");
+    });
+
+    it("Should initialize the config and appoint a moderator", async () => {
+      const [config_pkey, config_bump] = getConfigAddress(program.programId);
+
+      const signature = await program.methods.initializeConfig(new anchor.BN(report_threshold)).accounts(
+        {
+          authority: provider.wallet.publicKey,
+          config: config_pkey,
+          program: program.programId,
+          programData: getProgramDataAddress(program.programId),
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).rpc({ commitment: "confirmed" })
+
+      const initialized = (await getEvents(provider, program, signature)).find(e => e.name === "configInitialized");
+      assert.isDefined(initialized, "ConfigInitialized event should be emitted");
+      assert.strictEqual(initialized.data.reportThreshold.toNumber(), report_threshold, "ConfigInitialized should carry the report threshold");
+
+      await program.methods.addModerator(charlie.publicKey).accounts(
+        {
+          admin: provider.wallet.publicKey,
+          config: config_pkey,
+        }
+      ).rpc({ commitment: "confirmed" })
+
+      let configData = await program.account.programConfig.fetch(config_pkey);
+      assert.strictEqual(configData.admin.toString(), provider.wallet.publicKey.toString(), "Admin should be the upgrade authority");
+      assert.strictEqual(configData.reportThreshold.toNumber(), report_threshold, "Report threshold should match");
+      assert.deepEqual(configData.moderators.map((key) => key.toString()), [charlie.publicKey.toString()], "Charlie should be a moderator");
+    });
+
+    it("Should announce report threshold changes", async () => {
+      const [config_pkey, config_bump] = getConfigAddress(program.programId);
+
+      const signature = await program.methods.setReportThreshold(new anchor.BN(report_threshold)).accounts(
+        {
+          admin: provider.wallet.publicKey,
+          config: config_pkey,
+        }
+      ).rpc({ commitment: "confirmed" })
+
+      const updated = (await getEvents(provider, program, signature)).find(e => e.name === "configUpdated");
+      assert.isDefined(updated, "ConfigUpdated event should be emitted");
+      assert.strictEqual(updated.data.reportThreshold.toNumber(), report_threshold, "ConfigUpdated should carry the new report threshold");
+    });
+  });
+
   describe("Initialize Tweet", async () => {
     it("Should successfully initialize a tweet with valid topic and content", async () => {
       await airdrop(provider.connection, bob.publicKey);
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, bob.publicKey);
 
       await program.methods.initialize(topic_bob1, content_bob1).accounts(
         {
           tweetAuthority: bob.publicKey,
+          authorState: getAuthorStateAddress(bob.publicKey, program.programId)[0],
+          config: getConfigAddress(program.programId)[0],
           tweet: tweet_pkey,
+          topicIndex: getTopicIndexAddress(topic_bob1, program.programId)[0],
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([bob]).rpc({ commitment: "confirmed" })
+      registerTweetAddress(topic_bob1, bob.publicKey, [tweet_pkey, tweet_bump]);
 
       await checkTweet(
         program, tweet_pkey, bob.publicKey, topic_bob1, content_bob1, 0, 0, tweet_bump
@@ -67,15 +170,19 @@ describe("twitter", () => {
     });
 
     it("Should successfully initialize tweet with exactly 32-byte topic (boundary test)", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_edge_case, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, bob.publicKey);
 
       await program.methods.initialize(topic_edge_case, content_bob1).accounts(
         {
           tweetAuthority: bob.publicKey,
+          authorState: getAuthorStateAddress(bob.publicKey, program.programId)[0],
+          config: getConfigAddress(program.programId)[0],
           tweet: tweet_pkey,
+          topicIndex: getTopicIndexAddress(topic_edge_case, program.programId)[0],
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([bob]).rpc({ commitment: "confirmed" })
+      registerTweetAddress(topic_edge_case, bob.publicKey, [tweet_pkey, tweet_bump]);
 
       await checkTweet(
         program, tweet_pkey, bob.publicKey, topic_edge_case, content_bob1, 0, 0, tweet_bump
@@ -83,47 +190,108 @@ describe("twitter", () => {
     });
 
     it("Should successfully initialize tweet with exactly 500-byte content (boundary test)", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress("EdgeContent", bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, bob.publicKey);
 
       await program.methods.initialize("EdgeContent", content_edge_case).accounts(
         {
           tweetAuthority: bob.publicKey,
+          authorState: getAuthorStateAddress(bob.publicKey, program.programId)[0],
+          config: getConfigAddress(program.programId)[0],
           tweet: tweet_pkey,
+          topicIndex: getTopicIndexAddress("EdgeContent", program.programId)[0],
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([bob]).rpc({ commitment: "confirmed" })
+      registerTweetAddress("EdgeContent", bob.publicKey, [tweet_pkey, tweet_bump]);
 
       await checkTweet(
         program, tweet_pkey, bob.publicKey, "EdgeContent", content_edge_case, 0, 0, tweet_bump
       )
     });
 
-    it("Should successfully initialize tweet with empty content", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress("EmptyContent", bob.publicKey, program.programId);
+    it("Should fail to initialize tweet with empty content", async () => {
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, bob.publicKey);
 
-      await program.methods.initialize("EmptyContent", empty_content).accounts(
-        {
-          tweetAuthority: bob.publicKey,
-          tweet: tweet_pkey,
-          systemProgram: anchor.web3.SystemProgram.programId
-        }
-      ).signers([bob]).rpc({ commitment: "confirmed" })
+      let should_fail = "This Should Fail"
+      try {
+        await program.methods.initialize("EmptyContent", empty_content).accounts(
+          {
+            tweetAuthority: bob.publicKey,
+            authorState: getAuthorStateAddress(bob.publicKey, program.programId)[0],
+            config: getConfigAddress(program.programId)[0],
+            tweet: tweet_pkey,
+            topicIndex: getTopicIndexAddress("EmptyContent", program.programId)[0],
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([bob]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "ContentEmpty", "Expected 'ContentEmpty' error for empty content");
+        should_fail = "Failed"
+      }
+      assert.strictEqual(should_fail, "Failed", "Tweet initialization should have failed with empty content")
+    });
 
-      await checkTweet(
-        program, tweet_pkey, bob.publicKey, "EmptyContent", empty_content, 0, 0, tweet_bump
-      )
+    it("Should fail to initialize tweet with an empty topic", async () => {
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, bob.publicKey);
+
+      let should_fail = "This Should Fail"
+      try {
+        await program.methods.initialize(empty_topic, content_bob1).accounts(
+          {
+            tweetAuthority: bob.publicKey,
+            authorState: getAuthorStateAddress(bob.publicKey, program.programId)[0],
+            config: getConfigAddress(program.programId)[0],
+            tweet: tweet_pkey,
+            topicIndex: getTopicIndexAddress(empty_topic, program.programId)[0],
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([bob]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "TopicEmpty", "Expected 'TopicEmpty' error for an empty topic");
+        should_fail = "Failed"
+      }
+      assert.strictEqual(should_fail, "Failed", "Tweet initialization should have failed with an empty topic")
+    });
+
+    it("Should fail to initialize tweet with control characters in the content", async () => {
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, bob.publicKey);
+
+      let should_fail = "This Should Fail"
+      try {
+        await program.methods.initialize("ControlChars", "Ring the bell\u0007").accounts(
+          {
+            tweetAuthority: bob.publicKey,
+            authorState: getAuthorStateAddress(bob.publicKey, program.programId)[0],
+            config: getConfigAddress(program.programId)[0],
+            tweet: tweet_pkey,
+            topicIndex: getTopicIndexAddress("ControlChars", program.programId)[0],
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([bob]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "ContentInvalidCharacters", "Expected 'ContentInvalidCharacters' error for control characters");
+        should_fail = "Failed"
+      }
+      assert.strictEqual(should_fail, "Failed", "Tweet initialization should have failed with control characters in the content")
     });
 
     it("Should successfully initialize tweet with single character topic and content", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(single_char_topic, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, bob.publicKey);
 
       await program.methods.initialize(single_char_topic, single_char_content).accounts(
         {
           tweetAuthority: bob.publicKey,
+          authorState: getAuthorStateAddress(bob.publicKey, program.programId)[0],
+          config: getConfigAddress(program.programId)[0],
           tweet: tweet_pkey,
+          topicIndex: getTopicIndexAddress(single_char_topic, program.programId)[0],
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([bob]).rpc({ commitment: "confirmed" })
+      registerTweetAddress(single_char_topic, bob.publicKey, [tweet_pkey, tweet_bump]);
 
       await checkTweet(
         program, tweet_pkey, bob.publicKey, single_char_topic, single_char_content, 0, 0, tweet_bump
@@ -131,15 +299,19 @@ describe("twitter", () => {
     });
 
     it("Should successfully initialize tweet with unicode characters and emojis", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(unicode_topic, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, bob.publicKey);
 
       await program.methods.initialize(unicode_topic, unicode_content).accounts(
         {
           tweetAuthority: bob.publicKey,
+          authorState: getAuthorStateAddress(bob.publicKey, program.programId)[0],
+          config: getConfigAddress(program.programId)[0],
           tweet: tweet_pkey,
+          topicIndex: getTopicIndexAddress(unicode_topic, program.programId)[0],
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([bob]).rpc({ commitment: "confirmed" })
+      registerTweetAddress(unicode_topic, bob.publicKey, [tweet_pkey, tweet_bump]);
 
       await checkTweet(
         program, tweet_pkey, bob.publicKey, unicode_topic, unicode_content, 0, 0, tweet_bump
@@ -150,17 +322,21 @@ describe("twitter", () => {
 
       let should_fail = "This Should Fail"
       try {
-        const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob2, bob.publicKey, program.programId);
+        const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, bob.publicKey);
 
         await program.methods.initialize(topic_bob2, content_bob2).accounts(
           {
             tweetAuthority: bob.publicKey,
+            authorState: getAuthorStateAddress(bob.publicKey, program.programId)[0],
+            config: getConfigAddress(program.programId)[0],
             tweet: tweet_pkey,
+            topicIndex: getTopicIndexAddress(topic_bob2, program.programId)[0],
             systemProgram: anchor.web3.SystemProgram.programId
           }
         ).signers([bob]).rpc({ commitment: "confirmed" })
       } catch (error) {
-        assert.strictEqual(error.message, "Max seed length exceeded", "Expected 'Max seed length exceeded' error for topic longer than 32 bytes");
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "TopicTooLong", "Expected 'TopicTooLong' error for topic longer than 32 bytes");
         should_fail = "Failed"
       }
       assert.strictEqual(should_fail, "Failed", "Tweet initialization should have failed with topic longer than 32 bytes")
@@ -169,12 +345,15 @@ describe("twitter", () => {
     it("Should fail to initialize tweet when content exceeds 500 bytes", async () => {
       let should_fail = "This Should Fail"
       try {
-        const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob3, bob.publicKey, program.programId);
+        const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, bob.publicKey);
 
         await program.methods.initialize(topic_bob3, content_bob3).accounts(
           {
             tweetAuthority: bob.publicKey,
+            authorState: getAuthorStateAddress(bob.publicKey, program.programId)[0],
+            config: getConfigAddress(program.programId)[0],
             tweet: tweet_pkey,
+            topicIndex: getTopicIndexAddress(topic_bob3, program.programId)[0],
             systemProgram: anchor.web3.SystemProgram.programId
           }
         ).signers([bob]).rpc({ commitment: "confirmed" })
@@ -186,35 +365,67 @@ describe("twitter", () => {
       assert.strictEqual(should_fail, "Failed", "Tweet initialization should have failed with content longer than 500 bytes")
     });
 
-    it("Should fail to initialize duplicate tweet with same topic and author", async () => {
+    it("Should fail to initialize a tweet at its legacy topic-seeded address", async () => {
       let should_fail = "This Should Fail"
       try {
-        const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+        const [tweet_pkey, tweet_bump] = getLegacyTweetAddress(topic_bob1, bob.publicKey, program.programId);
 
         await program.methods.initialize(topic_bob1, "Different content").accounts(
           {
             tweetAuthority: bob.publicKey,
+            authorState: getAuthorStateAddress(bob.publicKey, program.programId)[0],
+            config: getConfigAddress(program.programId)[0],
+            tweet: tweet_pkey,
+            topicIndex: getTopicIndexAddress(topic_bob1, program.programId)[0],
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([bob]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "ConstraintSeeds", "Expected 'ConstraintSeeds' error for a topic-seeded tweet address");
+        should_fail = "Failed"
+      }
+      assert.strictEqual(should_fail, "Failed", "Tweet initialization should no longer create topic-seeded tweets")
+    });
+
+    it("Should fail to migrate a legacy tweet that does not exist", async () => {
+      let should_fail = "This Should Fail"
+      try {
+        const [legacy_tweet_pkey, legacy_tweet_bump] = getLegacyTweetAddress(topic_bob1, bob.publicKey, program.programId);
+        const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, bob.publicKey);
+
+        await program.methods.migrateLegacyTweet(topic_bob1).accounts(
+          {
+            tweetAuthor: bob.publicKey,
+            authorState: getAuthorStateAddress(bob.publicKey, program.programId)[0],
+            legacyTweet: legacy_tweet_pkey,
             tweet: tweet_pkey,
+            topicIndex: getTopicIndexAddress(topic_bob1, program.programId)[0],
             systemProgram: anchor.web3.SystemProgram.programId
           }
         ).signers([bob]).rpc({ commitment: "confirmed" })
       } catch (error) {
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "AccountOwnedByWrongProgram", "Expected 'AccountOwnedByWrongProgram' error for an empty legacy tweet address");
         should_fail = "Failed"
-        assert.isTrue(SolanaError.contains(error.logs, "already in use"), "Expected 'already in use' error for duplicate tweet with same topic and author")
       }
-      assert.strictEqual(should_fail, "Failed", "Tweet initialization should have failed when trying to create duplicate tweet with same topic and author")
+      assert.strictEqual(should_fail, "Failed", "Migrating a legacy tweet that was never created should have failed")
     });
 
     it("Should successfully initialize second tweet with different topic for same author", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, bob.publicKey);
 
       await program.methods.initialize(topic_bob4, content_bob4).accounts(
         {
           tweetAuthority: bob.publicKey,
+          authorState: getAuthorStateAddress(bob.publicKey, program.programId)[0],
+          config: getConfigAddress(program.programId)[0],
           tweet: tweet_pkey,
+          topicIndex: getTopicIndexAddress(topic_bob4, program.programId)[0],
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([bob]).rpc({ commitment: "confirmed" })
+      registerTweetAddress(topic_bob4, bob.publicKey, [tweet_pkey, tweet_bump]);
       await checkTweet(
         program, tweet_pkey, bob.publicKey, topic_bob4, content_bob4, 0, 0, tweet_bump
       )
@@ -222,15 +433,19 @@ describe("twitter", () => {
 
     it("Should allow different users to create tweets with same topic", async () => {
       await airdrop(provider.connection, charlie.publicKey);
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, charlie.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, charlie.publicKey);
 
       await program.methods.initialize(topic_bob1, "Charlie's version").accounts(
         {
           tweetAuthority: charlie.publicKey,
+          authorState: getAuthorStateAddress(charlie.publicKey, program.programId)[0],
+          config: getConfigAddress(program.programId)[0],
           tweet: tweet_pkey,
+          topicIndex: getTopicIndexAddress(topic_bob1, program.programId)[0],
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([charlie]).rpc({ commitment: "confirmed" })
+      registerTweetAddress(topic_bob1, charlie.publicKey, [tweet_pkey, tweet_bump]);
 
       await checkTweet(
         program, tweet_pkey, charlie.publicKey, topic_bob1, "Charlie's version", 0, 0, tweet_bump
@@ -238,11 +453,78 @@ describe("twitter", () => {
     });
   });
 
+  describe("Create Tweet", async () => {
+    const topic_sequenced = "Daily update";
+
+    // Charlie's tweet 0 was created through `initialize` above.
+    it("Should successfully create a sequence-numbered tweet", async () => {
+      const [author_state_pkey, author_state_bump] = getAuthorStateAddress(charlie.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = getSequencedTweetAddress(charlie.publicKey, 1, program.programId);
+
+      await program.methods.createTweet(topic_sequenced, "Day one").accounts(
+        {
+          tweetAuthority: charlie.publicKey,
+          config: getConfigAddress(program.programId)[0],
+          authorState: author_state_pkey,
+          tweet: tweet_pkey,
+          topicIndex: getTopicIndexAddress(topic_sequenced, program.programId)[0],
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([charlie]).rpc({ commitment: "confirmed" })
+
+      await checkTweet(
+        program, tweet_pkey, charlie.publicKey, topic_sequenced, "Day one", 0, 0, tweet_bump
+      )
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.tweetId.toString(), "1", "Charlie's second tweet should have id 1");
+    });
+
+    it("Should allow multiple tweets with the same topic from the same author", async () => {
+      const [author_state_pkey, author_state_bump] = getAuthorStateAddress(charlie.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, charlie.publicKey);
+
+      await program.methods.createTweet(topic_sequenced, "Day two").accounts(
+        {
+          tweetAuthority: charlie.publicKey,
+          config: getConfigAddress(program.programId)[0],
+          authorState: author_state_pkey,
+          tweet: tweet_pkey,
+          topicIndex: getTopicIndexAddress(topic_sequenced, program.programId)[0],
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([charlie]).rpc({ commitment: "confirmed" })
+
+      await checkTweet(
+        program, tweet_pkey, charlie.publicKey, topic_sequenced, "Day two", 0, 0, tweet_bump
+      )
+      let authorState = await program.account.authorState.fetch(author_state_pkey);
+      assert.strictEqual(authorState.tweetCount.toString(), "3", "Author state should count every tweet");
+    });
+
+    it("Should give tweets created through initialize a sequence number", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey);
+
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.tweetId.toString(), "0", "Bob's first tweet should have id 0");
+    });
+
+    it("Should record the creation time and slot of each tweet", async () => {
+      const [first_pkey, first_bump] = getSequencedTweetAddress(charlie.publicKey, 1, program.programId);
+      const [second_pkey, second_bump] = getSequencedTweetAddress(charlie.publicKey, 2, program.programId);
+
+      let first = await program.account.tweet.fetch(first_pkey);
+      let second = await program.account.tweet.fetch(second_pkey);
+      assert.isAtMost(Math.abs(first.createdAt.toNumber() - Date.now() / 1000), 120, "Tweet creation time should be recent");
+      assert.isTrue(second.createdSlot.gte(first.createdSlot), "Later tweets should not have an earlier creation slot");
+      assert.isTrue(second.createdAt.gte(first.createdAt), "Later tweets should not have an earlier creation time");
+    });
+  });
+
   describe("Add Reaction", async () => {
     it("Should successfully add like reaction to tweet", async () => {
       await airdrop(provider.connection, alice.publicKey);
 
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey);
       const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
 
       await program.methods.likeTweet().accounts(
@@ -250,6 +532,7 @@ describe("twitter", () => {
           reactionAuthor: alice.publicKey,
           tweetReaction: reaction_pkey,
           tweet: tweet_pkey,
+          authorBlock: await getAuthorBlockAddress(program, tweet_pkey, alice.publicKey),
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([alice]).rpc({ commitment: "confirmed" })
@@ -262,7 +545,7 @@ describe("twitter", () => {
     });
 
     it("Should correctly set reaction type to 'like' enum variant", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey);
       const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
 
       let reactionData = await program.account.reaction.fetch(reaction_pkey);
@@ -271,7 +554,7 @@ describe("twitter", () => {
 
     it("Should fail when attempting to like the same tweet twice", async () => {
 
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey);
       const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
 
       let should_fail = "This should fail";
@@ -281,6 +564,7 @@ describe("twitter", () => {
             reactionAuthor: alice.publicKey,
             tweetReaction: reaction_pkey,
             tweet: tweet_pkey,
+            authorBlock: await getAuthorBlockAddress(program, tweet_pkey, alice.publicKey),
             systemProgram: anchor.web3.SystemProgram.programId
           }
         ).signers([alice]).rpc({ commitment: "confirmed" })
@@ -296,7 +580,7 @@ describe("twitter", () => {
 
     it("Should fail when attempting to dislike a tweet that is already liked", async () => {
 
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey);
       const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
 
       let should_fail = "This should fail";
@@ -306,6 +590,7 @@ describe("twitter", () => {
             reactionAuthor: alice.publicKey,
             tweetReaction: reaction_pkey,
             tweet: tweet_pkey,
+            authorBlock: await getAuthorBlockAddress(program, tweet_pkey, alice.publicKey),
             systemProgram: anchor.web3.SystemProgram.programId
           }
         ).signers([alice]).rpc({ commitment: "confirmed" })
@@ -321,7 +606,7 @@ describe("twitter", () => {
     });
 
     it("Should successfully add dislike reaction to different tweet", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey);
       const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
 
       await program.methods.dislikeTweet().accounts(
@@ -329,6 +614,7 @@ describe("twitter", () => {
           reactionAuthor: alice.publicKey,
           tweetReaction: reaction_pkey,
           tweet: tweet_pkey,
+          authorBlock: await getAuthorBlockAddress(program, tweet_pkey, alice.publicKey),
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([alice]).rpc({ commitment: "confirmed" })
@@ -342,7 +628,7 @@ describe("twitter", () => {
     });
 
     it("Should correctly set reaction type to 'dislike' enum variant", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey);
       const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
 
       let reactionData = await program.account.reaction.fetch(reaction_pkey);
@@ -351,7 +637,7 @@ describe("twitter", () => {
 
     it("Should fail when attempting to dislike the same tweet twice", async () => {
 
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey);
       const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
 
       let should_fail = "This should fail";
@@ -361,6 +647,7 @@ describe("twitter", () => {
             reactionAuthor: alice.publicKey,
             tweetReaction: reaction_pkey,
             tweet: tweet_pkey,
+            authorBlock: await getAuthorBlockAddress(program, tweet_pkey, alice.publicKey),
             systemProgram: anchor.web3.SystemProgram.programId
           }
         ).signers([alice]).rpc({ commitment: "confirmed" })
@@ -380,6 +667,7 @@ describe("twitter", () => {
             reactionAuthor: alice.publicKey,
             tweetReaction: reaction_pkey,
             tweet: tweet_pkey,
+            authorBlock: await getAuthorBlockAddress(program, tweet_pkey, alice.publicKey),
             systemProgram: anchor.web3.SystemProgram.programId
           }
         ).signers([alice]).rpc({ commitment: "confirmed" })
@@ -394,7 +682,7 @@ describe("twitter", () => {
     });
 
     it("Should allow multiple users to react to the same tweet", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey);
       const [reaction_pkey, reaction_bump] = getReactionAddress(charlie.publicKey, tweet_pkey, program.programId);
 
       await program.methods.likeTweet().accounts(
@@ -402,6 +690,7 @@ describe("twitter", () => {
           reactionAuthor: charlie.publicKey,
           tweetReaction: reaction_pkey,
           tweet: tweet_pkey,
+          authorBlock: await getAuthorBlockAddress(program, tweet_pkey, charlie.publicKey),
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([charlie]).rpc({ commitment: "confirmed" })
@@ -415,8 +704,31 @@ describe("twitter", () => {
       )
     });
 
+    it("Should successfully add an emoji reaction to tweet", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(unicode_topic, bob.publicKey);
+      const [reaction_pkey, reaction_bump] = getReactionAddress(charlie.publicKey, tweet_pkey, program.programId);
+
+      await program.methods.reactTweet({ love: {} }).accounts(
+        {
+          reactionAuthor: charlie.publicKey,
+          tweetReaction: reaction_pkey,
+          tweet: tweet_pkey,
+          authorBlock: await getAuthorBlockAddress(program, tweet_pkey, charlie.publicKey),
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([charlie]).rpc({ commitment: "confirmed" })
+
+      await checkTweet(
+        program, tweet_pkey, bob.publicKey, unicode_topic, unicode_content, 0, 0, tweet_bump
+      )
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.reactions[REACTION_LOVE].toString(), "1", "Tweet should have one 'love' reaction");
+      let reactionData = await program.account.reaction.fetch(reaction_pkey);
+      assert.deepEqual(reactionData.reaction, { love: {} }, "Reaction type should be set to 'love' enum variant");
+    });
+
     it("Should fail when attempting to react to non-existent tweet", async () => {
-      const [fake_tweet_pkey, fake_tweet_bump] = getTweetAddress("NonExistent", bob.publicKey, program.programId);
+      const [fake_tweet_pkey, fake_tweet_bump] = getLegacyTweetAddress("NonExistent", bob.publicKey, program.programId);
       const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, fake_tweet_pkey, program.programId);
 
       let should_fail = "This should fail";
@@ -426,6 +738,7 @@ describe("twitter", () => {
             reactionAuthor: alice.publicKey,
             tweetReaction: reaction_pkey,
             tweet: fake_tweet_pkey,
+            authorBlock: await getAuthorBlockAddress(program, fake_tweet_pkey, alice.publicKey),
             systemProgram: anchor.web3.SystemProgram.programId
           }
         ).signers([alice]).rpc({ commitment: "confirmed" })
@@ -439,7 +752,7 @@ describe("twitter", () => {
 
   describe("Remove Reaction", async () => {
     it("Should successfully remove existing reaction from tweet", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey);
       const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
 
       await program.methods.reactionRemove().accounts(
@@ -456,7 +769,7 @@ describe("twitter", () => {
     });
 
     it("Should properly delete reaction account after removal", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey);
       const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
 
       let should_fail = "This should fail"
@@ -471,7 +784,7 @@ describe("twitter", () => {
 
     it("Should allow adding new reaction after previous reaction was removed", async () => {
 
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey);
       const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
 
       await program.methods.likeTweet().accounts(
@@ -479,6 +792,7 @@ describe("twitter", () => {
           reactionAuthor: alice.publicKey,
           tweetReaction: reaction_pkey,
           tweet: tweet_pkey,
+          authorBlock: await getAuthorBlockAddress(program, tweet_pkey, alice.publicKey),
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([alice]).rpc({ commitment: "confirmed" })
@@ -492,7 +806,7 @@ describe("twitter", () => {
     });
 
     it("Should fail when attempting to remove non-existent reaction", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, charlie.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, charlie.publicKey);
       const [reaction_pkey, reaction_bump] = getReactionAddress(bob.publicKey, tweet_pkey, program.programId);
 
       let should_fail = "This should fail";
@@ -512,7 +826,7 @@ describe("twitter", () => {
     });
 
     it("Should fail when attempting to remove another user's reaction", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey);
       const [reaction_pkey, reaction_bump] = getReactionAddress(charlie.publicKey, tweet_pkey, program.programId);
 
       let should_fail = "This should fail";
@@ -533,19 +847,77 @@ describe("twitter", () => {
     });
   });
 
+  describe("Change Reaction", async () => {
+    it("Should switch an existing like to a dislike in place", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress("EdgeContent", bob.publicKey);
+      const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
+
+      await program.methods.likeTweet().accounts(
+        {
+          reactionAuthor: alice.publicKey,
+          tweetReaction: reaction_pkey,
+          tweet: tweet_pkey,
+          authorBlock: await getAuthorBlockAddress(program, tweet_pkey, alice.publicKey),
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      await program.methods.changeReaction({ dislike: {} }).accounts(
+        {
+          reactionAuthor: alice.publicKey,
+          tweetReaction: reaction_pkey,
+          tweet: tweet_pkey,
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      await checkTweet(
+        program, tweet_pkey, bob.publicKey, "EdgeContent", content_edge_case, 0, 1, tweet_bump
+      )
+      let reactionData = await program.account.reaction.fetch(reaction_pkey);
+      assert.deepEqual(reactionData.reaction, { dislike: {} }, "Reaction type should be switched to 'dislike' enum variant");
+    });
+
+    it("Should fail when changing a reaction to the same type", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress("EdgeContent", bob.publicKey);
+      const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.changeReaction({ dislike: {} }).accounts(
+          {
+            reactionAuthor: alice.publicKey,
+            tweetReaction: reaction_pkey,
+            tweet: tweet_pkey,
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "ReactionUnchanged", "Expected 'ReactionUnchanged' error when the reaction type does not change");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to change a reaction to the same type");
+      await checkTweet(
+        program, tweet_pkey, bob.publicKey, "EdgeContent", content_edge_case, 0, 1, tweet_bump
+      )
+    });
+  });
+
   describe("Add Comment", async () => {
     it("Should fail when attempting to add comment exceeding length limit", async () => {
 
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
-      const [comment_pkey, comment_bump] = getCommentAddress(comment_alice1, alice.publicKey, tweet_pkey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey);
+      const [comment_pkey, comment_bump] = await getNextCommentAddress(program, tweet_pkey);
 
       let should_fail = "This Should Fail"
       try {
         await program.methods.commentTweet(comment_alice1).accounts(
           {
             commentAuthor: alice.publicKey,
+            authorState: getAuthorStateAddress(alice.publicKey, program.programId)[0],
+            config: getConfigAddress(program.programId)[0],
             comment: comment_pkey,
-            tweet: tweet_pkey,
+            parentTweet: tweet_pkey,
+            authorBlock: await getAuthorBlockAddress(program, tweet_pkey, alice.publicKey),
             systemProgram: anchor.web3.SystemProgram.programId
           }
         ).signers([alice]).rpc({ commitment: "confirmed" })
@@ -559,32 +931,41 @@ describe("twitter", () => {
     });
 
     it("Should successfully add comment with valid length to tweet", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
-      const [comment_pkey, comment_bump] = getCommentAddress(comment_alice2, alice.publicKey, tweet_pkey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey);
+      const [comment_pkey, comment_bump] = await getNextCommentAddress(program, tweet_pkey);
 
       await program.methods.commentTweet(comment_alice2).accounts(
         {
           commentAuthor: alice.publicKey,
+          authorState: getAuthorStateAddress(alice.publicKey, program.programId)[0],
+          config: getConfigAddress(program.programId)[0],
           comment: comment_pkey,
-          tweet: tweet_pkey,
+          parentTweet: tweet_pkey,
+          authorBlock: await getAuthorBlockAddress(program, tweet_pkey, alice.publicKey),
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([alice]).rpc({ commitment: "confirmed" })
       await checkComment(
         program, comment_pkey, alice.publicKey, tweet_pkey, comment_alice2, comment_bump
       )
+      let commentData = await program.account.comment.fetch(comment_pkey);
+      const expected_hash = crypto.createHash("sha256").update(comment_alice2, "utf8").digest();
+      assert.deepEqual(Buffer.from(commentData.contentHash), expected_hash, "Comment should store the hash of its content");
     });
 
     it("Should successfully add comment with exactly 500 characters (boundary test)", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey);
       const max_comment = "C".repeat(500);
-      const [comment_pkey, comment_bump] = getCommentAddress(max_comment, alice.publicKey, tweet_pkey, program.programId);
+      const [comment_pkey, comment_bump] = await getNextCommentAddress(program, tweet_pkey);
 
       await program.methods.commentTweet(max_comment).accounts(
         {
           commentAuthor: alice.publicKey,
+          authorState: getAuthorStateAddress(alice.publicKey, program.programId)[0],
+          config: getConfigAddress(program.programId)[0],
           comment: comment_pkey,
-          tweet: tweet_pkey,
+          parentTweet: tweet_pkey,
+          authorBlock: await getAuthorBlockAddress(program, tweet_pkey, alice.publicKey),
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([alice]).rpc({ commitment: "confirmed" })
@@ -594,35 +975,45 @@ describe("twitter", () => {
       )
     });
 
-    it("Should successfully add empty comment to tweet", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+    it("Should fail to add an empty comment to tweet", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey);
       const empty_comment = "";
-      const [comment_pkey, comment_bump] = getCommentAddress(empty_comment, alice.publicKey, tweet_pkey, program.programId);
-
-      await program.methods.commentTweet(empty_comment).accounts(
-        {
-          commentAuthor: alice.publicKey,
-          comment: comment_pkey,
-          tweet: tweet_pkey,
-          systemProgram: anchor.web3.SystemProgram.programId
-        }
-      ).signers([alice]).rpc({ commitment: "confirmed" })
+      const [comment_pkey, comment_bump] = await getNextCommentAddress(program, tweet_pkey);
 
-      await checkComment(
-        program, comment_pkey, alice.publicKey, tweet_pkey, empty_comment, comment_bump
-      )
+      let should_fail = "This Should Fail"
+      try {
+        await program.methods.commentTweet(empty_comment).accounts(
+          {
+            commentAuthor: alice.publicKey,
+            authorState: getAuthorStateAddress(alice.publicKey, program.programId)[0],
+            config: getConfigAddress(program.programId)[0],
+            comment: comment_pkey,
+            parentTweet: tweet_pkey,
+            authorBlock: await getAuthorBlockAddress(program, tweet_pkey, alice.publicKey),
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "CommentEmpty", "Expected 'CommentEmpty' error for an empty comment");
+        should_fail = "Failed"
+      }
+      assert.strictEqual(should_fail, "Failed", "Comment creation should have failed with an empty comment")
     });
 
     it("Should successfully add comment with unicode characters and emojis", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey);
       const unicode_comment = "Great tweet! 🎉✨ Love it! 💯";
-      const [comment_pkey, comment_bump] = getCommentAddress(unicode_comment, alice.publicKey, tweet_pkey, program.programId);
+      const [comment_pkey, comment_bump] = await getNextCommentAddress(program, tweet_pkey);
 
       await program.methods.commentTweet(unicode_comment).accounts(
         {
           commentAuthor: alice.publicKey,
+          authorState: getAuthorStateAddress(alice.publicKey, program.programId)[0],
+          config: getConfigAddress(program.programId)[0],
           comment: comment_pkey,
-          tweet: tweet_pkey,
+          parentTweet: tweet_pkey,
+          authorBlock: await getAuthorBlockAddress(program, tweet_pkey, alice.publicKey),
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([alice]).rpc({ commitment: "confirmed" })
@@ -633,15 +1024,18 @@ describe("twitter", () => {
     });
 
     it("Should allow multiple users to comment on the same tweet", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey);
       const charlie_comment = "Charlie's comment here";
-      const [comment_pkey, comment_bump] = getCommentAddress(charlie_comment, charlie.publicKey, tweet_pkey, program.programId);
+      const [comment_pkey, comment_bump] = await getNextCommentAddress(program, tweet_pkey);
 
       await program.methods.commentTweet(charlie_comment).accounts(
         {
           commentAuthor: charlie.publicKey,
+          authorState: getAuthorStateAddress(charlie.publicKey, program.programId)[0],
+          config: getConfigAddress(program.programId)[0],
           comment: comment_pkey,
-          tweet: tweet_pkey,
+          parentTweet: tweet_pkey,
+          authorBlock: await getAuthorBlockAddress(program, tweet_pkey, charlie.publicKey),
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([charlie]).rpc({ commitment: "confirmed" })
@@ -652,17 +1046,20 @@ describe("twitter", () => {
     });
 
     it("Should fail when attempting to comment on non-existent tweet", async () => {
-      const [fake_tweet_pkey, fake_tweet_bump] = getTweetAddress("FakeTweet", bob.publicKey, program.programId);
+      const [fake_tweet_pkey, fake_tweet_bump] = getLegacyTweetAddress("FakeTweet", bob.publicKey, program.programId);
       const test_comment = "This should fail";
-      const [comment_pkey, comment_bump] = getCommentAddress(test_comment, alice.publicKey, fake_tweet_pkey, program.programId);
+      const [comment_pkey, comment_bump] = getCommentAddress(fake_tweet_pkey, 0, program.programId);
 
       let should_fail = "This should fail";
       try {
         await program.methods.commentTweet(test_comment).accounts(
           {
             commentAuthor: alice.publicKey,
+            authorState: getAuthorStateAddress(alice.publicKey, program.programId)[0],
+            config: getConfigAddress(program.programId)[0],
             comment: comment_pkey,
-            tweet: fake_tweet_pkey,
+            parentTweet: fake_tweet_pkey,
+            authorBlock: await getAuthorBlockAddress(program, fake_tweet_pkey, alice.publicKey),
             systemProgram: anchor.web3.SystemProgram.programId
           }
         ).signers([alice]).rpc({ commitment: "confirmed" })
@@ -673,40 +1070,48 @@ describe("twitter", () => {
       assert.strictEqual(should_fail, "Failed", "Should not be able to comment on a non-existent tweet");
     });
 
-    it("Should fail when attempting to create duplicate comment with same content", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
-      const [comment_pkey, comment_bump] = getCommentAddress(comment_alice2, alice.publicKey, tweet_pkey, program.programId);
+    it("Should allow posting a comment with the same content twice", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey);
+      const [comment_pkey, comment_bump] = await getNextCommentAddress(program, tweet_pkey);
 
-      let should_fail = "This should fail";
-      try {
-        await program.methods.commentTweet(comment_alice2).accounts(
-          {
-            commentAuthor: alice.publicKey,
-            comment: comment_pkey,
-            tweet: tweet_pkey,
-            systemProgram: anchor.web3.SystemProgram.programId
-          }
-        ).signers([alice]).rpc({ commitment: "confirmed" })
-      } catch (error) {
-        should_fail = "Failed"
-        assert.isTrue(SolanaError.contains(error.logs, "already in use"), "Expected 'already in use' error when trying to create duplicate comment")
-      }
-      assert.strictEqual(should_fail, "Failed", "Should not be able to create duplicate comment with same content from same user");
+      await program.methods.commentTweet(comment_alice2).accounts(
+        {
+          commentAuthor: alice.publicKey,
+          authorState: getAuthorStateAddress(alice.publicKey, program.programId)[0],
+          config: getConfigAddress(program.programId)[0],
+          comment: comment_pkey,
+          parentTweet: tweet_pkey,
+          authorBlock: await getAuthorBlockAddress(program, tweet_pkey, alice.publicKey),
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      await checkComment(
+        program, comment_pkey, alice.publicKey, tweet_pkey, comment_alice2, comment_bump
+      )
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.commentCount.toString(), "2", "Tweet should count both comments");
+      assert.strictEqual(tweetData.nextCommentId.toString(), "2", "Tweet next comment id should be 2");
     });
   });
 
   describe("Remove Comment", async () => {
     it("Should successfully remove existing comment from tweet", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
-      const [comment_pkey, comment_bump] = getCommentAddress(comment_alice2, alice.publicKey, tweet_pkey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey);
+      const [comment_pkey, comment_bump] = getCommentAddress(tweet_pkey, 0, program.programId);
 
       await program.methods.commentRemove().accounts(
         {
           commentAuthor: alice.publicKey,
           comment: comment_pkey,
+          tweet: tweet_pkey,
+          parentComment: null,
         }
       ).signers([alice]).rpc({ commitment: "confirmed" })
 
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.commentCount.toString(), "1", "Tweet comment count should be decremented after removal");
+
       let thisShouldFail = "This should fail"
       try {
         let commentData = await program.account.comment.fetch(comment_pkey);
@@ -719,9 +1124,8 @@ describe("twitter", () => {
     });
 
     it("Should fail when attempting to remove non-existent comment", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
-      const fake_comment = "This comment doesn't exist";
-      const [comment_pkey, comment_bump] = getCommentAddress(fake_comment, alice.publicKey, tweet_pkey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey);
+      const [comment_pkey, comment_bump] = getCommentAddress(tweet_pkey, 999, program.programId);
 
       let should_fail = "This should fail";
       try {
@@ -729,6 +1133,8 @@ describe("twitter", () => {
           {
             commentAuthor: alice.publicKey,
             comment: comment_pkey,
+            tweet: tweet_pkey,
+            parentComment: null,
           }
         ).signers([alice]).rpc({ commitment: "confirmed" })
       } catch (error) {
@@ -739,9 +1145,9 @@ describe("twitter", () => {
     });
 
     it("Should fail when attempting to remove another user's comment", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
-      const unicode_comment = "Great tweet! 🎉✨ Love it! 💯";
-      const [comment_pkey, comment_bump] = getCommentAddress(unicode_comment, alice.publicKey, tweet_pkey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey);
+      // Alice's unicode comment was the second comment on this tweet
+      const [comment_pkey, comment_bump] = getCommentAddress(tweet_pkey, 1, program.programId);
 
       let should_fail = "This should fail";
       try {
@@ -749,6 +1155,8 @@ describe("twitter", () => {
           {
             commentAuthor: charlie.publicKey, // Charlie trying to remove Alice's comment
             comment: comment_pkey,
+            tweet: tweet_pkey,
+            parentComment: null,
           }
         ).signers([charlie]).rpc({ commitment: "confirmed" })
       } catch (error) {
@@ -758,29 +1166,1993 @@ describe("twitter", () => {
       assert.strictEqual(should_fail, "Failed", "Should not be able to remove someone else's comment (authorization check)");
     });
 
-    it("Should allow recreating comment with same content after deletion", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
-      const [comment_pkey, comment_bump] = getCommentAddress(comment_alice2, alice.publicKey, tweet_pkey, program.programId);
-
-      // Recreate the same comment that was deleted
-      await program.methods.commentTweet(comment_alice2).accounts(
-        {
-          commentAuthor: alice.publicKey,
-          comment: comment_pkey,
-          tweet: tweet_pkey,
-          systemProgram: anchor.web3.SystemProgram.programId
-        }
-      ).signers([alice]).rpc({ commitment: "confirmed" })
+    it("Should fail when removing a comment through a different tweet", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey);
+      const [other_tweet_pkey, other_tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey);
+      const [comment_pkey, comment_bump] = getCommentAddress(tweet_pkey, 1, program.programId);
 
-      await checkComment(
+      let should_fail = "This should fail";
+      try {
+        await program.methods.commentRemove().accounts(
+          {
+            commentAuthor: alice.publicKey,
+            comment: comment_pkey,
+            tweet: other_tweet_pkey,
+            parentComment: null,
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "ConstraintSeeds", "Expected 'ConstraintSeeds' error for a comment on another tweet");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to remove a comment through a different tweet");
+    });
+
+    it("Should allow recreating comment with same content after deletion", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey);
+      const [comment_pkey, comment_bump] = await getNextCommentAddress(program, tweet_pkey);
+
+      // Recreate the same comment that was deleted
+      await program.methods.commentTweet(comment_alice2).accounts(
+        {
+          commentAuthor: alice.publicKey,
+          authorState: getAuthorStateAddress(alice.publicKey, program.programId)[0],
+          config: getConfigAddress(program.programId)[0],
+          comment: comment_pkey,
+          parentTweet: tweet_pkey,
+          authorBlock: await getAuthorBlockAddress(program, tweet_pkey, alice.publicKey),
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      await checkComment(
         program, comment_pkey, alice.publicKey, tweet_pkey, comment_alice2, comment_bump
       )
     });
   });
 
+  describe("Reply To Comment", async () => {
+    it("Should successfully reply to an existing comment", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey);
+      const [parent_pkey, parent_bump] = getCommentAddress(tweet_pkey, 1, program.programId);
+      const [reply_pkey, reply_bump] = await getNextCommentAddress(program, tweet_pkey);
+      const reply = "Fair enough, Alice";
+
+      await program.methods.replyToComment(reply).accounts(
+        {
+          commentAuthor: bob.publicKey,
+          authorState: getAuthorStateAddress(bob.publicKey, program.programId)[0],
+          config: getConfigAddress(program.programId)[0],
+          comment: reply_pkey,
+          parentComment: parent_pkey,
+          parentTweet: tweet_pkey,
+          authorBlock: await getAuthorBlockAddress(program, tweet_pkey, bob.publicKey),
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      await checkComment(
+        program, reply_pkey, bob.publicKey, tweet_pkey, reply, reply_bump
+      )
+      let replyData = await program.account.comment.fetch(reply_pkey);
+      assert.strictEqual(replyData.parentComment.toString(), parent_pkey.toString(), "Reply should point at its parent comment");
+      assert.strictEqual(replyData.depth, 1, "Reply depth should be 1");
+      let parentData = await program.account.comment.fetch(parent_pkey);
+      assert.strictEqual(parentData.replyCount.toString(), "1", "Parent comment should count the reply");
+    });
+
+    it("Should fail when the parent comment belongs to a different tweet", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey);
+      const [other_tweet_pkey, other_tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey);
+      const [parent_pkey, parent_bump] = getCommentAddress(other_tweet_pkey, 0, program.programId);
+      const [reply_pkey, reply_bump] = await getNextCommentAddress(program, tweet_pkey);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.replyToComment("Wrong thread").accounts(
+          {
+            commentAuthor: bob.publicKey,
+            authorState: getAuthorStateAddress(bob.publicKey, program.programId)[0],
+            config: getConfigAddress(program.programId)[0],
+            comment: reply_pkey,
+            parentComment: parent_pkey,
+            parentTweet: tweet_pkey,
+            authorBlock: await getAuthorBlockAddress(program, tweet_pkey, bob.publicKey),
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([bob]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "CommentParentMismatch", "Expected 'CommentParentMismatch' error for a parent comment on another tweet");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to reply to a comment on a different tweet");
+    });
+
+    it("Should decrement the parent reply counter when a reply is removed", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey);
+      const [parent_pkey, parent_bump] = getCommentAddress(tweet_pkey, 1, program.programId);
+      const [reply_pkey, reply_bump] = getCommentAddress(tweet_pkey, 3, program.programId);
+
+      await program.methods.commentRemove().accounts(
+        {
+          commentAuthor: bob.publicKey,
+          comment: reply_pkey,
+          tweet: tweet_pkey,
+          parentComment: parent_pkey,
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      let parentData = await program.account.comment.fetch(parent_pkey);
+      assert.strictEqual(parentData.replyCount.toString(), "0", "Parent comment reply count should be decremented");
+    });
+
+    it("Should fail when a reply would nest deeper than the maximum depth", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey);
+      let [parent_pkey, parent_bump] = getCommentAddress(tweet_pkey, 1, program.programId);
+
+      async function reply(parent: PublicKey, content: string) {
+        const [reply_pkey, reply_bump] = await getNextCommentAddress(program, tweet_pkey);
+        await program.methods.replyToComment(content).accounts(
+          {
+            commentAuthor: bob.publicKey,
+            authorState: getAuthorStateAddress(bob.publicKey, program.programId)[0],
+            config: getConfigAddress(program.programId)[0],
+            comment: reply_pkey,
+            parentComment: parent,
+            parentTweet: tweet_pkey,
+            authorBlock: await getAuthorBlockAddress(program, tweet_pkey, bob.publicKey),
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([bob]).rpc({ commitment: "confirmed" })
+        return reply_pkey;
+      }
+
+      for (let depth = 1; depth <= MAX_REPLY_DEPTH; depth++) {
+        parent_pkey = await reply(parent_pkey, `Reply at depth ${depth}`);
+      }
+      let deepestData = await program.account.comment.fetch(parent_pkey);
+      assert.strictEqual(deepestData.depth, MAX_REPLY_DEPTH, "Deepest reply should sit at the maximum depth");
+
+      let should_fail = "This should fail";
+      try {
+        await reply(parent_pkey, "One level too deep");
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "ReplyDepthExceeded", "Expected 'ReplyDepthExceeded' error past the maximum depth");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to reply deeper than the maximum depth");
+    });
+  });
+
+  describe("Retweet", async () => {
+    it("Should successfully retweet another user's tweet", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey);
+      const [retweet_pkey, retweet_bump] = getRetweetAddress(charlie.publicKey, tweet_pkey, program.programId);
+
+      await program.methods.retweet().accounts(
+        {
+          retweeter: charlie.publicKey,
+          originalTweet: tweet_pkey,
+          retweet: retweet_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([charlie]).rpc({ commitment: "confirmed" })
+
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.retweetCount.toString(), "1", "Tweet should have one retweet");
+      let retweetData = await program.account.retweet.fetch(retweet_pkey);
+      assert.strictEqual(retweetData.retweeter.toString(), charlie.publicKey.toString(), "Retweeter should be Charlie");
+      assert.strictEqual(retweetData.originalTweet.toString(), tweet_pkey.toString(), "Retweet should point at the original tweet");
+    });
+
+    it("Should fail when retweeting the same tweet twice", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey);
+      const [retweet_pkey, retweet_bump] = getRetweetAddress(charlie.publicKey, tweet_pkey, program.programId);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.retweet().accounts(
+          {
+            retweeter: charlie.publicKey,
+            originalTweet: tweet_pkey,
+            retweet: retweet_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([charlie]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        assert.isTrue(SolanaError.contains(error.logs, "already in use"), "Expected 'already in use' error when retweeting twice")
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to retweet the same tweet twice");
+    });
+
+    it("Should successfully undo a retweet", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey);
+      const [retweet_pkey, retweet_bump] = getRetweetAddress(charlie.publicKey, tweet_pkey, program.programId);
+
+      await program.methods.undoRetweet().accounts(
+        {
+          retweeter: charlie.publicKey,
+          originalTweet: tweet_pkey,
+          retweet: retweet_pkey,
+        }
+      ).signers([charlie]).rpc({ commitment: "confirmed" })
+
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.retweetCount.toString(), "0", "Tweet retweet count should be decremented");
+      assert.isNull(await provider.connection.getAccountInfo(retweet_pkey), "Retweet account should be closed after undo");
+    });
+
+    it("Should successfully quote another user's tweet", async () => {
+      const [quoted_pkey, quoted_bump] = getTweetAddress(topic_bob1, bob.publicKey);
+      const [author_state_pkey, author_state_bump] = getAuthorStateAddress(alice.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, alice.publicKey);
+
+      await program.methods.quoteTweet("Quoting Bob", "Couldn't agree more").accounts(
+        {
+          newTweet: {
+            tweetAuthority: alice.publicKey,
+            config: getConfigAddress(program.programId)[0],
+            authorState: author_state_pkey,
+            tweet: tweet_pkey,
+            topicIndex: getTopicIndexAddress("Quoting Bob", program.programId)[0],
+            systemProgram: anchor.web3.SystemProgram.programId
+          },
+          quotedTweet: quoted_pkey
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      await checkTweet(
+        program, tweet_pkey, alice.publicKey, "Quoting Bob", "Couldn't agree more", 0, 0, tweet_bump
+      )
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.quotedTweet.toString(), quoted_pkey.toString(), "Quote tweet should reference the quoted tweet");
+    });
+  });
+
+  describe("Tip Tweet", async () => {
+    it("Should transfer lamports to the tweet author and track the total", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey);
+      const amount = 1_000_000;
+      const balanceBefore = await provider.connection.getBalance(bob.publicKey);
+
+      await program.methods.tipTweet(new anchor.BN(amount)).accounts(
+        {
+          tipper: alice.publicKey,
+          tweet: tweet_pkey,
+          tweetAuthor: bob.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      const balanceAfter = await provider.connection.getBalance(bob.publicKey);
+      assert.strictEqual(balanceAfter - balanceBefore, amount, "Tweet author should receive the tipped lamports");
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.tipsReceived.toString(), amount.toString(), "Tweet should track the tipped amount");
+    });
+
+    it("Should fail when tipping zero lamports", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.tipTweet(new anchor.BN(0)).accounts(
+          {
+            tipper: alice.publicKey,
+            tweet: tweet_pkey,
+            tweetAuthor: bob.publicKey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "ZeroTipAmount", "Expected 'ZeroTipAmount' error for a zero tip");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to tip zero lamports");
+    });
+
+    it("Should fail when tipping your own tweet", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.tipTweet(new anchor.BN(1000)).accounts(
+          {
+            tipper: bob.publicKey,
+            tweet: tweet_pkey,
+            tweetAuthor: bob.publicKey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([bob]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "CannotTipSelf", "Expected 'CannotTipSelf' error when tipping your own tweet");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to tip your own tweet");
+    });
+  });
+
+  describe("Tip Tweet Token", async () => {
+    const mint = anchor.web3.Keypair.generate();
+
+    it("Should transfer tokens to the author's token account and record them in the ledger", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey);
+      const [ledger_pkey, ledger_bump] = getTipLedgerAddress(tweet_pkey, mint.publicKey, program.programId);
+      const alice_ata = getAssociatedTokenAddress(mint.publicKey, alice.publicKey);
+      const bob_ata = getAssociatedTokenAddress(mint.publicKey, bob.publicKey);
+
+      await createMintWithBalance(provider, mint, alice, 1_000_000);
+
+      await program.methods.tipTweetToken(new anchor.BN(250_000)).accounts(
+        {
+          tipper: alice.publicKey,
+          tweet: tweet_pkey,
+          tweetAuthor: bob.publicKey,
+          mint: mint.publicKey,
+          tipperTokenAccount: alice_ata,
+          authorTokenAccount: bob_ata,
+          tipLedger: ledger_pkey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      const bobBalance = await provider.connection.getTokenAccountBalance(bob_ata);
+      assert.strictEqual(bobBalance.value.amount, "250000", "Tweet author should receive the tipped tokens");
+      let ledgerData = await program.account.tipLedger.fetch(ledger_pkey);
+      assert.strictEqual(ledgerData.totalAmount.toString(), "250000", "Tip ledger should track the tipped amount");
+      assert.strictEqual(ledgerData.tipCount.toString(), "1", "Tip ledger should count the tip");
+      assert.strictEqual(ledgerData.mint.toString(), mint.publicKey.toString(), "Tip ledger should be keyed by the mint");
+      assert.strictEqual(ledgerData.payer.toString(), alice.publicKey.toString(), "Tip ledger should record Alice as the rent payer");
+    });
+
+    it("Should tip in a Token-2022 mint", async () => {
+      const mint_2022 = anchor.web3.Keypair.generate();
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey);
+      const [ledger_pkey, ledger_bump] = getTipLedgerAddress(tweet_pkey, mint_2022.publicKey, program.programId);
+      const alice_ata = getAssociatedTokenAddress(mint_2022.publicKey, alice.publicKey, TOKEN_2022_PROGRAM_ID);
+      const bob_ata = getAssociatedTokenAddress(mint_2022.publicKey, bob.publicKey, TOKEN_2022_PROGRAM_ID);
+
+      await createMintWithBalance(provider, mint_2022, alice, 1_000_000, TOKEN_2022_PROGRAM_ID);
+
+      await program.methods.tipTweetToken(new anchor.BN(100_000)).accounts(
+        {
+          tipper: alice.publicKey,
+          tweet: tweet_pkey,
+          tweetAuthor: bob.publicKey,
+          mint: mint_2022.publicKey,
+          tipperTokenAccount: alice_ata,
+          authorTokenAccount: bob_ata,
+          tipLedger: ledger_pkey,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      const bobBalance = await provider.connection.getTokenAccountBalance(bob_ata);
+      assert.strictEqual(bobBalance.value.amount, "100000", "Tweet author should receive the Token-2022 tip");
+      let ledgerData = await program.account.tipLedger.fetch(ledger_pkey);
+      assert.strictEqual(ledgerData.totalAmount.toString(), "100000", "Tip ledger should track the Token-2022 tip");
+      assert.strictEqual(ledgerData.mint.toString(), mint_2022.publicKey.toString(), "Tip ledger should be keyed by the Token-2022 mint");
+    });
+  });
+
+  describe("Bookmark", async () => {
+    it("Should bookmark a tweet and count it on the user's state", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey);
+      const [author_state_pkey, author_state_bump] = getAuthorStateAddress(charlie.publicKey, program.programId);
+      const [bookmark_pkey, bookmark_bump] = getBookmarkAddress(charlie.publicKey, tweet_pkey, program.programId);
+
+      const signature = await program.methods.bookmarkTweet().accounts(
+        {
+          user: charlie.publicKey,
+          tweet: tweet_pkey,
+          authorState: author_state_pkey,
+          bookmark: bookmark_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([charlie]).rpc({ commitment: "confirmed" })
+
+      const bookmarked = (await getEvents(provider, program, signature)).find(e => e.name === "tweetBookmarked");
+      assert.isDefined(bookmarked, "TweetBookmarked event should be emitted");
+      assert.strictEqual(bookmarked.data.tweet.toString(), tweet_pkey.toString(), "Event should reference the bookmarked tweet");
+      let bookmarkData = await program.account.bookmark.fetch(bookmark_pkey);
+      assert.strictEqual(bookmarkData.user.toString(), charlie.publicKey.toString(), "Bookmark should belong to Charlie");
+      assert.strictEqual(bookmarkData.tweet.toString(), tweet_pkey.toString(), "Bookmark should point at the tweet");
+      assert.strictEqual(bookmarkData.bump, bookmark_bump, "Bookmark bump should match");
+      let authorStateData = await program.account.authorState.fetch(author_state_pkey);
+      assert.strictEqual(authorStateData.bookmarkCount.toString(), "1", "User should have one bookmark");
+    });
+
+    it("Should fail when bookmarking the same tweet twice", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey);
+      const [author_state_pkey, author_state_bump] = getAuthorStateAddress(charlie.publicKey, program.programId);
+      const [bookmark_pkey, bookmark_bump] = getBookmarkAddress(charlie.publicKey, tweet_pkey, program.programId);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.bookmarkTweet().accounts(
+          {
+            user: charlie.publicKey,
+            tweet: tweet_pkey,
+            authorState: author_state_pkey,
+            bookmark: bookmark_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([charlie]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        assert.isTrue(SolanaError.contains(error.logs, "already in use"), "Expected 'already in use' error when bookmarking twice")
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to bookmark the same tweet twice");
+    });
+
+    it("Should remove a bookmark and refund its rent to the user", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey);
+      const [author_state_pkey, author_state_bump] = getAuthorStateAddress(charlie.publicKey, program.programId);
+      const [bookmark_pkey, bookmark_bump] = getBookmarkAddress(charlie.publicKey, tweet_pkey, program.programId);
+      const bookmarkRent = await provider.connection.getBalance(bookmark_pkey);
+      const balanceBefore = await provider.connection.getBalance(charlie.publicKey);
+
+      const signature = await program.methods.removeBookmark().accounts(
+        {
+          user: charlie.publicKey,
+          authorState: author_state_pkey,
+          bookmark: bookmark_pkey,
+        }
+      ).signers([charlie]).rpc({ commitment: "confirmed" })
+
+      const balanceAfter = await provider.connection.getBalance(charlie.publicKey);
+      assert.isNull(await provider.connection.getAccountInfo(bookmark_pkey), "Bookmark account should be closed");
+      assert.isAbove(balanceAfter, balanceBefore + bookmarkRent - 10_000, "Bookmark rent should be refunded to the user");
+      let authorStateData = await program.account.authorState.fetch(author_state_pkey);
+      assert.strictEqual(authorStateData.bookmarkCount.toString(), "0", "User bookmark count should be decremented");
+      const removed = (await getEvents(provider, program, signature)).find(e => e.name === "bookmarkRemoved");
+      assert.isDefined(removed, "BookmarkRemoved event should be emitted");
+    });
+  });
+
+  describe("Poll", async () => {
+    it("Should fail when the poll end timestamp is in the past", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey);
+      const [poll_pkey, poll_bump] = getPollAddress(tweet_pkey, program.programId);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.createPoll(["Yes", "No"], new anchor.BN(1)).accounts(
+          {
+            tweetAuthor: bob.publicKey,
+            tweet: tweet_pkey,
+            poll: poll_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([bob]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "PollEndInPast", "Expected 'PollEndInPast' error for an expired poll");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to create a poll that has already ended");
+    });
+
+    it("Should fail when a poll has a single option", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey);
+      const [poll_pkey, poll_bump] = getPollAddress(tweet_pkey, program.programId);
+      const end = Math.floor(Date.now() / 1000) + 3600;
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.createPoll(["Yes"], new anchor.BN(end)).accounts(
+          {
+            tweetAuthor: bob.publicKey,
+            tweet: tweet_pkey,
+            poll: poll_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([bob]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "InvalidPollOptionCount", "Expected 'InvalidPollOptionCount' error for a single option");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to create a poll with a single option");
+    });
+
+    it("Should fail when a poll option is empty", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey);
+      const [poll_pkey, poll_bump] = getPollAddress(tweet_pkey, program.programId);
+      const end = Math.floor(Date.now() / 1000) + 3600;
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.createPoll(["Yes", ""], new anchor.BN(end)).accounts(
+          {
+            tweetAuthor: bob.publicKey,
+            tweet: tweet_pkey,
+            poll: poll_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([bob]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "PollOptionEmpty", "Expected 'PollOptionEmpty' error for an empty option");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to create a poll with an empty option");
+    });
+
+    it("Should fail when a poll option contains control characters", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey);
+      const [poll_pkey, poll_bump] = getPollAddress(tweet_pkey, program.programId);
+      const end = Math.floor(Date.now() / 1000) + 3600;
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.createPoll(["Yes", "No\nway"], new anchor.BN(end)).accounts(
+          {
+            tweetAuthor: bob.publicKey,
+            tweet: tweet_pkey,
+            poll: poll_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([bob]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "PollOptionInvalidCharacters", "Expected 'PollOptionInvalidCharacters' error for an option with a newline");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to create a poll with a multi-line option");
+    });
+
+    it("Should create a poll on the author's tweet and record a vote", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey);
+      const [poll_pkey, poll_bump] = getPollAddress(tweet_pkey, program.programId);
+      const [vote_pkey, vote_bump] = getPollVoteAddress(poll_pkey, alice.publicKey, program.programId);
+      const end = Math.floor(Date.now() / 1000) + 3600;
+
+      await program.methods.createPoll(["Yes", "No", "Maybe"], new anchor.BN(end)).accounts(
+        {
+          tweetAuthor: bob.publicKey,
+          tweet: tweet_pkey,
+          poll: poll_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      await program.methods.votePoll(2).accounts(
+        {
+          voter: alice.publicKey,
+          poll: poll_pkey,
+          pollVote: vote_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      let pollData = await program.account.poll.fetch(poll_pkey);
+      assert.strictEqual(pollData.tweetAuthor.toString(), bob.publicKey.toString(), "Poll should record the tweet author");
+      assert.deepEqual(pollData.options, ["Yes", "No", "Maybe"], "Poll options should match");
+      assert.deepEqual(pollData.tallies.map((tally) => tally.toNumber()), [0, 0, 1, 0], "Vote should be tallied on the chosen option");
+      let voteData = await program.account.pollVote.fetch(vote_pkey);
+      assert.strictEqual(voteData.optionIndex, 2, "Vote should record the chosen option");
+    });
+
+    it("Should fail when voting twice on the same poll", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey);
+      const [poll_pkey, poll_bump] = getPollAddress(tweet_pkey, program.programId);
+      const [vote_pkey, vote_bump] = getPollVoteAddress(poll_pkey, alice.publicKey, program.programId);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.votePoll(0).accounts(
+          {
+            voter: alice.publicKey,
+            poll: poll_pkey,
+            pollVote: vote_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        assert.isTrue(SolanaError.contains(error.logs, "already in use"), "Expected 'already in use' error when voting twice")
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to vote twice on the same poll");
+    });
+
+    it("Should fail when voting after the poll has ended", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey);
+      const [poll_pkey, poll_bump] = getPollAddress(tweet_pkey, program.programId);
+      const end = (await provider.connection.getBlockTime(await provider.connection.getSlot("confirmed"))) + 2;
+
+      await program.methods.createPoll(["Now", "Later"], new anchor.BN(end)).accounts(
+        {
+          tweetAuthor: bob.publicKey,
+          tweet: tweet_pkey,
+          poll: poll_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      // Wait until the cluster clock has passed the poll's end.
+      while ((await provider.connection.getBlockTime(await provider.connection.getSlot("confirmed"))) <= end) {
+        await new Promise((resolve) => setTimeout(resolve, 500));
+      }
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.votePoll(0).accounts(
+          {
+            voter: charlie.publicKey,
+            poll: poll_pkey,
+            pollVote: getPollVoteAddress(poll_pkey, charlie.publicKey, program.programId)[0],
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([charlie]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "PollClosed", "Expected 'PollClosed' error when voting after the end timestamp");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to vote on a poll that has ended");
+    });
+  });
+
+  describe("Edit Tweet", async () => {
+    it("Should successfully edit own tweet and record the edit", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(single_char_topic, bob.publicKey);
+      const edited_content = "Edited content";
+      const sizeBefore = (await provider.connection.getAccountInfo(tweet_pkey)).data.length;
+      const previous_content = (await program.account.tweet.fetch(tweet_pkey)).content;
+
+      await program.methods.editTweet(edited_content).accounts(
+        {
+          tweetAuthor: bob.publicKey,
+          tweet: tweet_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      await checkTweet(
+        program, tweet_pkey, bob.publicKey, single_char_topic, edited_content, 0, 0, tweet_bump
+      )
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.editCount.toString(), "1", "Tweet edit count should be 1 after first edit");
+      assert.isTrue(tweetData.lastEditedAt.gtn(0), "Tweet last edited timestamp should be set");
+      const sizeAfter = (await provider.connection.getAccountInfo(tweet_pkey)).data.length;
+      assert.strictEqual(sizeAfter - sizeBefore, Buffer.byteLength(edited_content) - Buffer.byteLength(previous_content), "Tweet account should be resized to the new content");
+    });
+
+    it("Should refund rent to the author when the content gets shorter", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(single_char_topic, bob.publicKey);
+      const lamportsBefore = (await provider.connection.getAccountInfo(tweet_pkey)).lamports;
+      const balanceBefore = await provider.connection.getBalance(bob.publicKey);
+
+      await program.methods.editTweet(single_char_content).accounts(
+        {
+          tweetAuthor: bob.publicKey,
+          tweet: tweet_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      const lamportsAfter = (await provider.connection.getAccountInfo(tweet_pkey)).lamports;
+      const balanceAfter = await provider.connection.getBalance(bob.publicKey);
+      assert.isBelow(lamportsAfter, lamportsBefore, "Shrunk tweet account should hold less rent");
+      assert.strictEqual(balanceAfter - balanceBefore, lamportsBefore - lamportsAfter, "Rent difference should be refunded to the author");
+      await checkTweet(
+        program, tweet_pkey, bob.publicKey, single_char_topic, single_char_content, 0, 0, tweet_bump
+      )
+    });
+
+    it("Should fail when attempting to edit another user's tweet", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(single_char_topic, bob.publicKey);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.editTweet("Alice was here").accounts(
+          {
+            tweetAuthor: alice.publicKey,
+            tweet: tweet_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "ConstraintHasOne", "Expected 'ConstraintHasOne' error when editing someone else's tweet");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to edit someone else's tweet");
+    });
+
+    it("Should fail to edit tweet when new content exceeds 500 bytes", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(single_char_topic, bob.publicKey);
+
+      let should_fail = "This Should Fail"
+      try {
+        await program.methods.editTweet(content_bob3).accounts(
+          {
+            tweetAuthor: bob.publicKey,
+            tweet: tweet_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([bob]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "ContentTooLong", "Expected 'ContentTooLong' error for edited content longer than 500 bytes");
+        should_fail = "Failed"
+      }
+      assert.strictEqual(should_fail, "Failed", "Tweet edit should have failed with content longer than 500 bytes")
+    });
+  });
+
+  describe("Delete Tweet", async () => {
+    const topic_delete = "Short lived";
+    const comment_delete = "Commenting before it is gone";
+    const tip_mint = anchor.web3.Keypair.generate();
+
+    before(async () => {
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, alice.publicKey);
+      const [reaction_pkey, reaction_bump] = getReactionAddress(bob.publicKey, tweet_pkey, program.programId);
+      const [comment_pkey, comment_bump] = getCommentAddress(tweet_pkey, 0, program.programId);
+
+      await program.methods.initialize(topic_delete, "This tweet will be deleted").accounts(
+        {
+          tweetAuthority: alice.publicKey,
+          authorState: getAuthorStateAddress(alice.publicKey, program.programId)[0],
+          config: getConfigAddress(program.programId)[0],
+          tweet: tweet_pkey,
+          topicIndex: getTopicIndexAddress(topic_delete, program.programId)[0],
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+      registerTweetAddress(topic_delete, alice.publicKey, [tweet_pkey, tweet_bump]);
+      await program.methods.likeTweet().accounts(
+        {
+          reactionAuthor: bob.publicKey,
+          tweetReaction: reaction_pkey,
+          tweet: tweet_pkey,
+          authorBlock: await getAuthorBlockAddress(program, tweet_pkey, bob.publicKey),
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+      await program.methods.commentTweet(comment_delete).accounts(
+        {
+          commentAuthor: bob.publicKey,
+          authorState: getAuthorStateAddress(bob.publicKey, program.programId)[0],
+          config: getConfigAddress(program.programId)[0],
+          comment: comment_pkey,
+          parentTweet: tweet_pkey,
+          authorBlock: await getAuthorBlockAddress(program, tweet_pkey, bob.publicKey),
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+      await program.methods.retweet().accounts(
+        {
+          retweeter: charlie.publicKey,
+          originalTweet: tweet_pkey,
+          retweet: getRetweetAddress(charlie.publicKey, tweet_pkey, program.programId)[0],
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([charlie]).rpc({ commitment: "confirmed" })
+      const [poll_pkey, poll_bump] = getPollAddress(tweet_pkey, program.programId);
+      await program.methods.createPoll(["Keep", "Delete"], new anchor.BN(Math.floor(Date.now() / 1000) + 3600)).accounts(
+        {
+          tweetAuthor: alice.publicKey,
+          tweet: tweet_pkey,
+          poll: poll_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+      await program.methods.votePoll(1).accounts(
+        {
+          voter: bob.publicKey,
+          poll: poll_pkey,
+          pollVote: getPollVoteAddress(poll_pkey, bob.publicKey, program.programId)[0],
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+      await program.methods.reportTweet({ spam: {} }).accounts(
+        {
+          reporter: bob.publicKey,
+          tweet: tweet_pkey,
+          config: getConfigAddress(program.programId)[0],
+          report: getReportAddress(tweet_pkey, bob.publicKey, program.programId)[0],
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+      await createMintWithBalance(provider, tip_mint, bob, 1_000);
+      await program.methods.tipTweetToken(new anchor.BN(10)).accounts(
+        {
+          tipper: bob.publicKey,
+          tweet: tweet_pkey,
+          tweetAuthor: alice.publicKey,
+          mint: tip_mint.publicKey,
+          tipperTokenAccount: getAssociatedTokenAddress(tip_mint.publicKey, bob.publicKey),
+          authorTokenAccount: getAssociatedTokenAddress(tip_mint.publicKey, alice.publicKey),
+          tipLedger: getTipLedgerAddress(tweet_pkey, tip_mint.publicKey, program.programId)[0],
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+    });
+
+    it("Should fail when reclaiming a reaction whose tweet still exists", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_delete, alice.publicKey);
+      const [reaction_pkey, reaction_bump] = getReactionAddress(bob.publicKey, tweet_pkey, program.programId);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.reclaimReaction().accounts(
+          {
+            reactionAuthor: bob.publicKey,
+            tweet: tweet_pkey,
+            tweetReaction: reaction_pkey,
+          }
+        ).signers([bob]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "ParentTweetStillExists", "Expected 'ParentTweetStillExists' error when the tweet was not deleted");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to reclaim a reaction on a live tweet");
+    });
+
+    it("Should fail when reclaiming a vote on a poll that is still open", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_delete, alice.publicKey);
+      const [poll_pkey, poll_bump] = getPollAddress(tweet_pkey, program.programId);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.reclaimPollVote().accounts(
+          {
+            voter: bob.publicKey,
+            poll: poll_pkey,
+            pollVote: getPollVoteAddress(poll_pkey, bob.publicKey, program.programId)[0],
+          }
+        ).signers([bob]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "PollStillOpen", "Expected 'PollStillOpen' error before the poll ends");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to withdraw a vote from an open poll");
+    });
+
+    it("Should fail when attempting to delete another user's tweet", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_delete, alice.publicKey);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.deleteTweet().accounts(
+          {
+            tweetAuthor: bob.publicKey,
+            tweet: tweet_pkey,
+            topicIndex: getTopicIndexAddress(topic_delete, program.programId)[0],
+          }
+        ).signers([bob]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "ConstraintHasOne", "Expected 'ConstraintHasOne' error when deleting someone else's tweet");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to delete someone else's tweet");
+    });
+
+    it("Should successfully delete own tweet", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_delete, alice.publicKey);
+
+      await program.methods.deleteTweet().accounts(
+        {
+          tweetAuthor: alice.publicKey,
+          tweet: tweet_pkey,
+          topicIndex: getTopicIndexAddress(topic_delete, program.programId)[0],
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      const tweetInfo = await provider.connection.getAccountInfo(tweet_pkey);
+      assert.isNull(tweetInfo, "Tweet account should be closed after deletion");
+    });
+
+    it("Should let authors reclaim reactions and comments of a deleted tweet", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_delete, alice.publicKey);
+      const [reaction_pkey, reaction_bump] = getReactionAddress(bob.publicKey, tweet_pkey, program.programId);
+      const [comment_pkey, comment_bump] = getCommentAddress(tweet_pkey, 0, program.programId);
+
+      await program.methods.reclaimReaction().accounts(
+        {
+          reactionAuthor: bob.publicKey,
+          tweet: tweet_pkey,
+          tweetReaction: reaction_pkey,
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+      await program.methods.reclaimComment().accounts(
+        {
+          commentAuthor: bob.publicKey,
+          tweet: tweet_pkey,
+          comment: comment_pkey,
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      assert.isNull(await provider.connection.getAccountInfo(reaction_pkey), "Reaction account should be closed after reclaim");
+      assert.isNull(await provider.connection.getAccountInfo(comment_pkey), "Comment account should be closed after reclaim");
+    });
+
+    it("Should let retweeters reclaim retweets of a deleted tweet", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_delete, alice.publicKey);
+      const [retweet_pkey, retweet_bump] = getRetweetAddress(charlie.publicKey, tweet_pkey, program.programId);
+
+      await program.methods.reclaimRetweet().accounts(
+        {
+          retweeter: charlie.publicKey,
+          originalTweet: tweet_pkey,
+          retweet: retweet_pkey,
+        }
+      ).signers([charlie]).rpc({ commitment: "confirmed" })
+
+      assert.isNull(await provider.connection.getAccountInfo(retweet_pkey), "Retweet account should be closed after reclaim");
+    });
+
+    it("Should let the author reclaim the poll and voters their votes of a deleted tweet", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_delete, alice.publicKey);
+      const [poll_pkey, poll_bump] = getPollAddress(tweet_pkey, program.programId);
+      const [vote_pkey, vote_bump] = getPollVoteAddress(poll_pkey, bob.publicKey, program.programId);
+
+      await program.methods.reclaimPoll().accounts(
+        {
+          tweetAuthor: alice.publicKey,
+          tweet: tweet_pkey,
+          poll: poll_pkey,
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+      await program.methods.reclaimPollVote().accounts(
+        {
+          voter: bob.publicKey,
+          poll: poll_pkey,
+          pollVote: vote_pkey,
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      assert.isNull(await provider.connection.getAccountInfo(poll_pkey), "Poll account should be closed after reclaim");
+      assert.isNull(await provider.connection.getAccountInfo(vote_pkey), "Vote account should be closed after reclaim");
+    });
+
+    it("Should let reporters reclaim reports of a deleted tweet", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_delete, alice.publicKey);
+      const [report_pkey, report_bump] = getReportAddress(tweet_pkey, bob.publicKey, program.programId);
+
+      await program.methods.reclaimReport().accounts(
+        {
+          reporter: bob.publicKey,
+          tweet: tweet_pkey,
+          report: report_pkey,
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      assert.isNull(await provider.connection.getAccountInfo(report_pkey), "Report account should be closed after reclaim");
+    });
+
+    it("Should let the tipper who paid for it reclaim the tip ledger of a deleted tweet", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_delete, alice.publicKey);
+      const [ledger_pkey, ledger_bump] = getTipLedgerAddress(tweet_pkey, tip_mint.publicKey, program.programId);
+
+      await program.methods.reclaimTipLedger().accounts(
+        {
+          payer: bob.publicKey,
+          tweet: tweet_pkey,
+          tipLedger: ledger_pkey,
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      assert.isNull(await provider.connection.getAccountInfo(ledger_pkey), "Tip ledger account should be closed after reclaim");
+    });
+
+    it("Should give a re-created tweet a fresh address so new comments do not collide", async () => {
+      const [deleted_pkey, deleted_bump] = getTweetAddress(topic_delete, alice.publicKey);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, alice.publicKey);
+      assert.notStrictEqual(tweet_pkey.toString(), deleted_pkey.toString(), "Re-created tweet should not reuse the deleted tweet's address");
+
+      await program.methods.initialize(topic_delete, "Back again").accounts(
+        {
+          tweetAuthority: alice.publicKey,
+          authorState: getAuthorStateAddress(alice.publicKey, program.programId)[0],
+          config: getConfigAddress(program.programId)[0],
+          tweet: tweet_pkey,
+          topicIndex: getTopicIndexAddress(topic_delete, program.programId)[0],
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      const [comment_pkey, comment_bump] = getCommentAddress(tweet_pkey, 0, program.programId);
+      await program.methods.commentTweet(comment_delete).accounts(
+        {
+          commentAuthor: bob.publicKey,
+          authorState: getAuthorStateAddress(bob.publicKey, program.programId)[0],
+          config: getConfigAddress(program.programId)[0],
+          comment: comment_pkey,
+          parentTweet: tweet_pkey,
+          authorBlock: await getAuthorBlockAddress(program, tweet_pkey, bob.publicKey),
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      await checkComment(
+        program, comment_pkey, bob.publicKey, tweet_pkey, comment_delete, comment_bump
+      )
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.commentCount.toString(), "1", "Re-created tweet should only count its own comment");
+    });
+  });
+
+  describe("User Profile", async () => {
+    it("Should successfully create a profile with a unique handle", async () => {
+      const [profile_pkey, profile_bump] = getProfileAddress(alice.publicKey, program.programId);
+      const [handle_pkey, handle_bump] = getHandleAddress("alice", program.programId);
+
+      await program.methods.createProfile("alice", "Alice", "Just here for the memes", "https://example.com/alice.png").accounts(
+        {
+          authority: alice.publicKey,
+          userProfile: profile_pkey,
+          handleAccount: handle_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      let profileData = await program.account.userProfile.fetch(profile_pkey);
+      assert.strictEqual(profileData.authority.toString(), alice.publicKey.toString(), "Profile authority should be Alice");
+      assert.strictEqual(profileData.handle, "alice", "Profile handle should be 'alice'");
+      assert.strictEqual(profileData.displayName, "Alice", "Profile display name should be 'Alice'");
+      let handleData = await program.account.handle.fetch(handle_pkey);
+      assert.strictEqual(handleData.authority.toString(), alice.publicKey.toString(), "Handle should be owned by Alice");
+    });
+
+    it("Should fail when claiming a handle that is already taken", async () => {
+      const [profile_pkey, profile_bump] = getProfileAddress(bob.publicKey, program.programId);
+      const [handle_pkey, handle_bump] = getHandleAddress("alice", program.programId);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.createProfile("alice", "Fake Alice", "", "").accounts(
+          {
+            authority: bob.publicKey,
+            userProfile: profile_pkey,
+            handleAccount: handle_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([bob]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "HandleTaken", "Expected 'HandleTaken' error when claiming someone else's handle");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to claim a handle that is already taken");
+    });
+
+    it("Should fail when creating a profile with an invalid handle", async () => {
+      const [profile_pkey, profile_bump] = getProfileAddress(bob.publicKey, program.programId);
+      const [handle_pkey, handle_bump] = getHandleAddress("Bob!", program.programId);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.createProfile("Bob!", "Bob", "", "").accounts(
+          {
+            authority: bob.publicKey,
+            userProfile: profile_pkey,
+            handleAccount: handle_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([bob]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "InvalidHandle", "Expected 'InvalidHandle' error for a handle with uppercase letters and punctuation");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to create a profile with an invalid handle");
+    });
+
+    it("Should successfully update own profile", async () => {
+      const [profile_pkey, profile_bump] = getProfileAddress(alice.publicKey, program.programId);
+
+      await program.methods.updateProfile("Alice in Chains", "New bio", "").accounts(
+        {
+          authority: alice.publicKey,
+          userProfile: profile_pkey,
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      let profileData = await program.account.userProfile.fetch(profile_pkey);
+      assert.strictEqual(profileData.displayName, "Alice in Chains", "Profile display name should be updated");
+      assert.strictEqual(profileData.bio, "New bio", "Profile bio should be updated");
+      assert.strictEqual(profileData.handle, "alice", "Profile handle should be unchanged");
+    });
+
+    it("Should fail when the display name contains control characters", async () => {
+      const [profile_pkey, profile_bump] = getProfileAddress(alice.publicKey, program.programId);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.updateProfile("Alice\u0007", "New bio", "").accounts(
+          {
+            authority: alice.publicKey,
+            userProfile: profile_pkey,
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "DisplayNameInvalidCharacters", "Expected 'DisplayNameInvalidCharacters' error for a display name with a control character");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to set a display name with control characters");
+    });
+
+    it("Should let another user claim a handle after it was released", async () => {
+      const [alice_profile_pkey, alice_profile_bump] = getProfileAddress(alice.publicKey, program.programId);
+      const [bob_profile_pkey, bob_profile_bump] = getProfileAddress(bob.publicKey, program.programId);
+      const [handle_pkey, handle_bump] = getHandleAddress("alice", program.programId);
+
+      await program.methods.releaseHandle().accounts(
+        {
+          authority: alice.publicKey,
+          userProfile: alice_profile_pkey,
+          handleAccount: handle_pkey,
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      let aliceProfile = await program.account.userProfile.fetch(alice_profile_pkey);
+      assert.strictEqual(aliceProfile.handle, "", "Alice's profile handle should be cleared after release");
+
+      await program.methods.createProfile("alice", "Bob", "", "").accounts(
+        {
+          authority: bob.publicKey,
+          userProfile: bob_profile_pkey,
+          handleAccount: handle_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      let handleData = await program.account.handle.fetch(handle_pkey);
+      assert.strictEqual(handleData.authority.toString(), bob.publicKey.toString(), "Released handle should now belong to Bob");
+    });
+
+    it("Should let a profile without a handle claim a new one", async () => {
+      const [profile_pkey, profile_bump] = getProfileAddress(alice.publicKey, program.programId);
+      const [handle_pkey, handle_bump] = getHandleAddress("alice_again", program.programId);
+
+      await program.methods.claimHandle("alice_again").accounts(
+        {
+          authority: alice.publicKey,
+          userProfile: profile_pkey,
+          handleAccount: handle_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      let profileData = await program.account.userProfile.fetch(profile_pkey);
+      assert.strictEqual(profileData.handle, "alice_again", "Alice's profile should carry the new handle");
+      let handleData = await program.account.handle.fetch(handle_pkey);
+      assert.strictEqual(handleData.authority.toString(), alice.publicKey.toString(), "New handle should belong to Alice");
+    });
+
+    it("Should fail when claiming a handle while the profile still has one", async () => {
+      const [profile_pkey, profile_bump] = getProfileAddress(alice.publicKey, program.programId);
+      const [handle_pkey, handle_bump] = getHandleAddress("alice_third", program.programId);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.claimHandle("alice_third").accounts(
+          {
+            authority: alice.publicKey,
+            userProfile: profile_pkey,
+            handleAccount: handle_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "HandleAlreadySet", "Expected 'HandleAlreadySet' error when the profile already has a handle");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to hold two handles at once");
+    });
+  });
+
+  describe("Follow", async () => {
+    it("Should successfully follow another user", async () => {
+      const [alice_profile_pkey, alice_profile_bump] = getProfileAddress(alice.publicKey, program.programId);
+      const [bob_profile_pkey, bob_profile_bump] = getProfileAddress(bob.publicKey, program.programId);
+      const [follow_pkey, follow_bump] = getFollowAddress(alice.publicKey, bob.publicKey, program.programId);
+
+      await program.methods.follow().accounts(
+        {
+          follower: alice.publicKey,
+          followerProfile: alice_profile_pkey,
+          followeeProfile: bob_profile_pkey,
+          follow: follow_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      let aliceProfile = await program.account.userProfile.fetch(alice_profile_pkey);
+      let bobProfile = await program.account.userProfile.fetch(bob_profile_pkey);
+      assert.strictEqual(aliceProfile.followingCount.toString(), "1", "Alice should be following one user");
+      assert.strictEqual(bobProfile.followerCount.toString(), "1", "Bob should have one follower");
+    });
+
+    it("Should fail when following the same user twice", async () => {
+      const [alice_profile_pkey, alice_profile_bump] = getProfileAddress(alice.publicKey, program.programId);
+      const [bob_profile_pkey, bob_profile_bump] = getProfileAddress(bob.publicKey, program.programId);
+      const [follow_pkey, follow_bump] = getFollowAddress(alice.publicKey, bob.publicKey, program.programId);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.follow().accounts(
+          {
+            follower: alice.publicKey,
+            followerProfile: alice_profile_pkey,
+            followeeProfile: bob_profile_pkey,
+            follow: follow_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "AlreadyFollowing", "Expected 'AlreadyFollowing' error when following twice");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to follow the same user twice");
+    });
+
+    it("Should fail when following yourself", async () => {
+      const [alice_profile_pkey, alice_profile_bump] = getProfileAddress(alice.publicKey, program.programId);
+      const [follow_pkey, follow_bump] = getFollowAddress(alice.publicKey, alice.publicKey, program.programId);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.follow().accounts(
+          {
+            follower: alice.publicKey,
+            followerProfile: alice_profile_pkey,
+            followeeProfile: alice_profile_pkey,
+            follow: follow_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "CannotFollowSelf", "Expected 'CannotFollowSelf' error when following yourself");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to follow yourself");
+    });
+
+    it("Should successfully unfollow a user", async () => {
+      const [alice_profile_pkey, alice_profile_bump] = getProfileAddress(alice.publicKey, program.programId);
+      const [bob_profile_pkey, bob_profile_bump] = getProfileAddress(bob.publicKey, program.programId);
+      const [follow_pkey, follow_bump] = getFollowAddress(alice.publicKey, bob.publicKey, program.programId);
+
+      await program.methods.unfollow().accounts(
+        {
+          follower: alice.publicKey,
+          followerProfile: alice_profile_pkey,
+          followeeProfile: bob_profile_pkey,
+          follow: follow_pkey,
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      let aliceProfile = await program.account.userProfile.fetch(alice_profile_pkey);
+      let bobProfile = await program.account.userProfile.fetch(bob_profile_pkey);
+      assert.strictEqual(aliceProfile.followingCount.toString(), "0", "Alice should no longer follow anyone");
+      assert.strictEqual(bobProfile.followerCount.toString(), "0", "Bob should have no followers");
+      assert.isNull(await provider.connection.getAccountInfo(follow_pkey), "Follow account should be closed after unfollow");
+    });
+  });
+
+  describe("Block", async () => {
+    const topic_block = "Blocked out";
+
+    it("Should fail when blocking yourself", async () => {
+      const [block_pkey, block_bump] = getBlockAddress(bob.publicKey, bob.publicKey, program.programId);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.blockUser().accounts(
+          {
+            blocker: bob.publicKey,
+            blocked: bob.publicKey,
+            block: block_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([bob]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "CannotBlockSelf", "Expected 'CannotBlockSelf' error when blocking yourself");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to block yourself");
+    });
+
+    it("Should stop a blocked user from commenting on the blocker's tweets", async () => {
+      const [block_pkey, block_bump] = getBlockAddress(bob.publicKey, alice.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey);
+      const [comment_pkey, comment_bump] = await getNextCommentAddress(program, tweet_pkey);
+
+      await program.methods.blockUser().accounts(
+        {
+          blocker: bob.publicKey,
+          blocked: alice.publicKey,
+          block: block_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      let blockData = await program.account.block.fetch(block_pkey);
+      assert.strictEqual(blockData.blocked.toString(), alice.publicKey.toString(), "Block should target Alice");
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.commentTweet("You can't block me").accounts(
+          {
+            commentAuthor: alice.publicKey,
+            authorState: getAuthorStateAddress(alice.publicKey, program.programId)[0],
+            config: getConfigAddress(program.programId)[0],
+            comment: comment_pkey,
+            parentTweet: tweet_pkey,
+            authorBlock: block_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "Blocked", "Expected 'Blocked' error for a blocked commenter");
+      }
+      assert.strictEqual(should_fail, "Failed", "Blocked users should not be able to comment");
+    });
+
+    it("Should stop a blocked user from liking the blocker's tweets", async () => {
+      const [block_pkey, block_bump] = getBlockAddress(bob.publicKey, alice.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, bob.publicKey);
+      const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
+
+      await program.methods.createTweet(topic_block, "Alice can't react to this").accounts(
+        {
+          tweetAuthority: bob.publicKey,
+          authorState: getAuthorStateAddress(bob.publicKey, program.programId)[0],
+          config: getConfigAddress(program.programId)[0],
+          tweet: tweet_pkey,
+          topicIndex: getTopicIndexAddress(topic_block, program.programId)[0],
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+      registerTweetAddress(topic_block, bob.publicKey, [tweet_pkey, tweet_bump]);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.likeTweet().accounts(
+          {
+            reactionAuthor: alice.publicKey,
+            tweetReaction: reaction_pkey,
+            tweet: tweet_pkey,
+            authorBlock: block_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "Blocked", "Expected 'Blocked' error for a blocked user liking a tweet");
+      }
+      assert.strictEqual(should_fail, "Failed", "Blocked users should not be able to like");
+    });
+
+    it("Should stop a blocked user from reacting to the blocker's tweets", async () => {
+      const [block_pkey, block_bump] = getBlockAddress(bob.publicKey, alice.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_block, bob.publicKey);
+      const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.reactTweet({ laugh: {} }).accounts(
+          {
+            reactionAuthor: alice.publicKey,
+            tweetReaction: reaction_pkey,
+            tweet: tweet_pkey,
+            authorBlock: block_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "Blocked", "Expected 'Blocked' error for a blocked user reacting to a tweet");
+      }
+      assert.strictEqual(should_fail, "Failed", "Blocked users should not be able to react");
+    });
+
+    it("Should stop a blocked user from replying to comments on the blocker's tweets", async () => {
+      const [block_pkey, block_bump] = getBlockAddress(bob.publicKey, alice.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey);
+      const [parent_pkey, parent_bump] = getCommentAddress(tweet_pkey, 1, program.programId);
+      const [reply_pkey, reply_bump] = await getNextCommentAddress(program, tweet_pkey);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.replyToComment("Replying anyway").accounts(
+          {
+            commentAuthor: alice.publicKey,
+            authorState: getAuthorStateAddress(alice.publicKey, program.programId)[0],
+            config: getConfigAddress(program.programId)[0],
+            comment: reply_pkey,
+            parentComment: parent_pkey,
+            parentTweet: tweet_pkey,
+            authorBlock: block_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "Blocked", "Expected 'Blocked' error for a blocked user replying to a comment");
+      }
+      assert.strictEqual(should_fail, "Failed", "Blocked users should not be able to reply");
+    });
+
+    it("Should let the blocker unblock the user", async () => {
+      const [block_pkey, block_bump] = getBlockAddress(bob.publicKey, alice.publicKey, program.programId);
+
+      await program.methods.unblockUser().accounts(
+        {
+          blocker: bob.publicKey,
+          block: block_pkey,
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      assert.isNull(await provider.connection.getAccountInfo(block_pkey), "Block account should be closed after unblocking");
+    });
+  });
+
+  describe("Hashtags and Mentions", async () => {
+    it("Should store normalised hashtags and emit mention events", async () => {
+      const [author_state_pkey] = getAuthorStateAddress(alice.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, alice.publicKey);
+      const [handle_pkey] = getHandleAddress("alice", program.programId);
+
+      const tx = await program.methods.createTweet("Tags", "gm #Solana #solana #Anchor_Dev cc @Alice").accounts(
+        {
+          tweetAuthority: alice.publicKey,
+          config: getConfigAddress(program.programId)[0],
+          authorState: author_state_pkey,
+          tweet: tweet_pkey,
+          topicIndex: getTopicIndexAddress("Tags", program.programId)[0],
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).remainingAccounts([
+        { pubkey: handle_pkey, isSigner: false, isWritable: false }
+      ]).signers([alice]).rpc({ commitment: "confirmed" })
+
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.deepEqual(tweetData.hashtags, ["solana", "anchor_dev"], "Hashtags should be lowercased and deduplicated");
+
+      const mention = (await getEvents(provider, program, tx)).find(e => e.name === "tweetMention");
+      assert.isDefined(mention, "TweetMention event should be emitted");
+      // The "alice" handle was released by Alice and claimed by Bob earlier
+      assert.strictEqual(mention.data.mentioned.toString(), bob.publicKey.toString(), "Mention should resolve to the handle owner");
+      assert.strictEqual(mention.data.handle, "alice", "Mention should carry the normalised handle");
+    });
+
+    it("Should fail when the content has too many hashtags", async () => {
+      const [author_state_pkey] = getAuthorStateAddress(alice.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, alice.publicKey);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.createTweet("Tags", "#one #two #three #four #five #six").accounts(
+          {
+            tweetAuthority: alice.publicKey,
+            config: getConfigAddress(program.programId)[0],
+            authorState: author_state_pkey,
+            tweet: tweet_pkey,
+            topicIndex: getTopicIndexAddress("Tags", program.programId)[0],
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "TooManyHashtags", "Expected 'TooManyHashtags' error for six hashtags");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to tweet more than five hashtags");
+    });
+
+    it("Should fail when a passed handle is not mentioned in the content", async () => {
+      const [author_state_pkey] = getAuthorStateAddress(alice.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, alice.publicKey);
+      const [handle_pkey] = getHandleAddress("alice", program.programId);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.createTweet("Tags", "No mentions here").accounts(
+          {
+            tweetAuthority: alice.publicKey,
+            config: getConfigAddress(program.programId)[0],
+            authorState: author_state_pkey,
+            tweet: tweet_pkey,
+            topicIndex: getTopicIndexAddress("Tags", program.programId)[0],
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).remainingAccounts([
+          { pubkey: handle_pkey, isSigner: false, isWritable: false }
+        ]).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "InvalidMention", "Expected 'InvalidMention' error for a handle missing from the content");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to notify a handle that is not mentioned");
+    });
+
+    it("Should fail when the content has too many mentions", async () => {
+      const [author_state_pkey] = getAuthorStateAddress(alice.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, alice.publicKey);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.createTweet("Tags", "@one @two @three @four @five @six").accounts(
+          {
+            tweetAuthority: alice.publicKey,
+            config: getConfigAddress(program.programId)[0],
+            authorState: author_state_pkey,
+            tweet: tweet_pkey,
+            topicIndex: getTopicIndexAddress("Tags", program.programId)[0],
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "TooManyMentions", "Expected 'TooManyMentions' error for six mentions");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to mention more than five handles");
+    });
+
+    it("Should fail when a hashtag is longer than 32 bytes", async () => {
+      const [author_state_pkey] = getAuthorStateAddress(alice.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, alice.publicKey);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.createTweet("Tags", "#" + "a".repeat(33)).accounts(
+          {
+            tweetAuthority: alice.publicKey,
+            config: getConfigAddress(program.programId)[0],
+            authorState: author_state_pkey,
+            tweet: tweet_pkey,
+            topicIndex: getTopicIndexAddress("Tags", program.programId)[0],
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "HashtagTooLong", "Expected 'HashtagTooLong' error for a 33 byte hashtag");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to tweet a hashtag longer than 32 bytes");
+    });
+
+    it("Should fail when the same handle account is passed twice", async () => {
+      const [author_state_pkey] = getAuthorStateAddress(alice.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, alice.publicKey);
+      const [handle_pkey] = getHandleAddress("alice", program.programId);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.createTweet("Tags", "gm @alice").accounts(
+          {
+            tweetAuthority: alice.publicKey,
+            config: getConfigAddress(program.programId)[0],
+            authorState: author_state_pkey,
+            tweet: tweet_pkey,
+            topicIndex: getTopicIndexAddress("Tags", program.programId)[0],
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).remainingAccounts([
+          { pubkey: handle_pkey, isSigner: false, isWritable: false },
+          { pubkey: handle_pkey, isSigner: false, isWritable: false }
+        ]).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "DuplicateMention", "Expected 'DuplicateMention' error for a repeated handle account");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to notify the same handle twice");
+    });
+  });
+
+  describe("Events", async () => {
+    it("Should emit TweetCreated and ReactionAdded events", async () => {
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, charlie.publicKey);
+      const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
+
+      const createTx = await program.methods.initialize("Events", "Indexers, this one is for you").accounts(
+        {
+          tweetAuthority: charlie.publicKey,
+          authorState: getAuthorStateAddress(charlie.publicKey, program.programId)[0],
+          config: getConfigAddress(program.programId)[0],
+          tweet: tweet_pkey,
+          topicIndex: getTopicIndexAddress("Events", program.programId)[0],
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([charlie]).rpc({ commitment: "confirmed" })
+      registerTweetAddress("Events", charlie.publicKey, [tweet_pkey, tweet_bump]);
+
+      const created = (await getEvents(provider, program, createTx)).find(e => e.name === "tweetCreated");
+      assert.isDefined(created, "TweetCreated event should be emitted");
+      assert.strictEqual(created.data.tweet.toString(), tweet_pkey.toString(), "TweetCreated should carry the tweet key");
+      assert.strictEqual(created.data.tweetAuthor.toString(), charlie.publicKey.toString(), "TweetCreated should carry the author");
+      assert.strictEqual(created.data.topic, "Events", "TweetCreated should carry the topic");
+
+      const reactTx = await program.methods.likeTweet().accounts(
+        {
+          reactionAuthor: alice.publicKey,
+          tweetReaction: reaction_pkey,
+          tweet: tweet_pkey,
+          authorBlock: await getAuthorBlockAddress(program, tweet_pkey, alice.publicKey),
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      const added = (await getEvents(provider, program, reactTx)).find(e => e.name === "reactionAdded");
+      assert.isDefined(added, "ReactionAdded event should be emitted");
+      assert.strictEqual(added.data.reactionAuthor.toString(), alice.publicKey.toString(), "ReactionAdded should carry the reaction author");
+      assert.deepEqual(added.data.reactionType, { like: {} }, "ReactionAdded should carry the reaction type");
+    });
+  });
+
+  describe("Topic Index", async () => {
+    it("Should track every tweet created under a topic", async () => {
+      const [index_pkey, index_bump] = getTopicIndexAddress("Daily update", program.programId);
+      const [first_pkey] = getSequencedTweetAddress(charlie.publicKey, 1, program.programId);
+      const [second_pkey] = getSequencedTweetAddress(charlie.publicKey, 2, program.programId);
+
+      let indexData = await program.account.topicIndex.fetch(index_pkey);
+      assert.strictEqual(indexData.topic, "Daily update", "Topic index should store its topic");
+      assert.strictEqual(indexData.tweetCount.toString(), "2", "Topic index should count both tweets");
+      assert.strictEqual(indexData.recentTweets[0].toString(), first_pkey.toString(), "First tweet should be in the first ring buffer slot");
+      assert.strictEqual(indexData.recentTweets[1].toString(), second_pkey.toString(), "Second tweet should be in the second ring buffer slot");
+      assert.strictEqual(indexData.nextSlot, 2, "Ring buffer should advance after each tweet");
+    });
+
+    it("Should drop deleted tweets from the topic index", async () => {
+      const [index_pkey, index_bump] = getTopicIndexAddress("Short lived", program.programId);
+
+      let indexData = await program.account.topicIndex.fetch(index_pkey);
+      // Only the tweet re-created under the same topic is still counted.
+      assert.strictEqual(indexData.tweetCount.toString(), "1", "Deleted tweet should no longer be counted");
+      assert.strictEqual(indexData.recentTweets[0].toString(), PublicKey.default.toString(), "Deleted tweet should be cleared from the ring buffer");
+    });
+  });
+
+  describe("Moderation", async () => {
+    it("Should let a moderator hide a tweet with a reason", async () => {
+      const [config_pkey, config_bump] = getConfigAddress(program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey);
+
+      const signature = await program.methods.hideTweet({ harassment: {} }).accounts(
+        {
+          moderator: charlie.publicKey,
+          config: config_pkey,
+          tweet: tweet_pkey,
+        }
+      ).signers([charlie]).rpc({ commitment: "confirmed" })
+
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.deepEqual(tweetData.hiddenReason, { harassment: {} }, "Tweet should be hidden for harassment");
+      const hidden = (await getEvents(provider, program, signature)).find(e => e.name === "tweetHidden");
+      assert.isDefined(hidden, "TweetHidden event should be emitted");
+      assert.strictEqual(hidden.data.moderator.toString(), charlie.publicKey.toString(), "TweetHidden should carry the moderator");
+    });
+
+    it("Should fail when a non-moderator hides a tweet", async () => {
+      const [config_pkey, config_bump] = getConfigAddress(program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.hideTweet({ spam: {} }).accounts(
+          {
+            moderator: alice.publicKey,
+            config: config_pkey,
+            tweet: tweet_pkey,
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "NotModerator", "Expected 'NotModerator' error for a regular user");
+      }
+      assert.strictEqual(should_fail, "Failed", "Regular users should not be able to hide tweets");
+    });
+
+    it("Should let a moderator hide a comment with a reason", async () => {
+      const [config_pkey, config_bump] = getConfigAddress(program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey);
+      const [comment_pkey, comment_bump] = getCommentAddress(tweet_pkey, 1, program.programId);
+
+      const signature = await program.methods.hideComment({ spam: {} }).accounts(
+        {
+          moderator: charlie.publicKey,
+          config: config_pkey,
+          comment: comment_pkey,
+        }
+      ).signers([charlie]).rpc({ commitment: "confirmed" })
+
+      let commentData = await program.account.comment.fetch(comment_pkey);
+      assert.deepEqual(commentData.hiddenReason, { spam: {} }, "Comment should be hidden as spam");
+      const hidden = (await getEvents(provider, program, signature)).find(e => e.name === "commentHidden");
+      assert.isDefined(hidden, "CommentHidden event should be emitted");
+      assert.strictEqual(hidden.data.tweet.toString(), tweet_pkey.toString(), "CommentHidden should carry the parent tweet");
+    });
+
+    it("Should let a moderator unhide a tweet", async () => {
+      const [config_pkey, config_bump] = getConfigAddress(program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey);
+
+      const signature = await program.methods.unhideTweet().accounts(
+        {
+          moderator: charlie.publicKey,
+          config: config_pkey,
+          tweet: tweet_pkey,
+        }
+      ).signers([charlie]).rpc({ commitment: "confirmed" })
+
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.isNull(tweetData.hiddenReason, "Tweet should no longer be hidden");
+      const unhidden = (await getEvents(provider, program, signature)).find(e => e.name === "tweetUnhidden");
+      assert.isDefined(unhidden, "TweetUnhidden event should be emitted");
+    });
+
+    it("Should let a moderator unhide a comment", async () => {
+      const [config_pkey, config_bump] = getConfigAddress(program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey);
+      const [comment_pkey, comment_bump] = getCommentAddress(tweet_pkey, 1, program.programId);
+
+      const signature = await program.methods.unhideComment().accounts(
+        {
+          moderator: charlie.publicKey,
+          config: config_pkey,
+          comment: comment_pkey,
+        }
+      ).signers([charlie]).rpc({ commitment: "confirmed" })
+
+      let commentData = await program.account.comment.fetch(comment_pkey);
+      assert.isNull(commentData.hiddenReason, "Comment should no longer be hidden");
+      const unhidden = (await getEvents(provider, program, signature)).find(e => e.name === "commentUnhidden");
+      assert.isDefined(unhidden, "CommentUnhidden event should be emitted");
+    });
+
+    it("Should fail when unhiding a tweet that is not hidden", async () => {
+      const [config_pkey, config_bump] = getConfigAddress(program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.unhideTweet().accounts(
+          {
+            moderator: charlie.publicKey,
+            config: config_pkey,
+            tweet: tweet_pkey,
+          }
+        ).signers([charlie]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "NotHidden", "Expected 'NotHidden' error for a visible tweet");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to unhide a tweet that is not hidden");
+    });
+  });
+
+  describe("Report Tweet", async () => {
+    it("Should fail when reporting your own tweet", async () => {
+      const [config_pkey, config_bump] = getConfigAddress(program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey);
+      const [report_pkey, report_bump] = getReportAddress(tweet_pkey, bob.publicKey, program.programId);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.reportTweet({ spam: {} }).accounts(
+          {
+            reporter: bob.publicKey,
+            tweet: tweet_pkey,
+            config: config_pkey,
+            report: report_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([bob]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "CannotReportSelf", "Expected 'CannotReportSelf' error when reporting your own tweet");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to report your own tweet");
+    });
+
+    it("Should count reports below the threshold without starting a review", async () => {
+      const [config_pkey, config_bump] = getConfigAddress(program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey);
+      const [report_pkey, report_bump] = getReportAddress(tweet_pkey, alice.publicKey, program.programId);
+
+      await program.methods.reportTweet({ misinformation: {} }).accounts(
+        {
+          reporter: alice.publicKey,
+          tweet: tweet_pkey,
+          config: config_pkey,
+          report: report_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      let reportData = await program.account.report.fetch(report_pkey);
+      assert.deepEqual(reportData.reason, { misinformation: {} }, "Report should store the reason");
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.reportCount.toString(), "1", "Tweet should have one report");
+      assert.isFalse(tweetData.underReview, "Tweet should not be under review below the threshold");
+    });
+
+    it("Should put the tweet under review once the threshold is reached", async () => {
+      const [config_pkey, config_bump] = getConfigAddress(program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey);
+      const [report_pkey, report_bump] = getReportAddress(tweet_pkey, charlie.publicKey, program.programId);
+
+      const signature = await program.methods.reportTweet({ spam: {} }).accounts(
+        {
+          reporter: charlie.publicKey,
+          tweet: tweet_pkey,
+          config: config_pkey,
+          report: report_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([charlie]).rpc({ commitment: "confirmed" })
+
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.reportCount.toString(), "2", "Tweet should have two reports");
+      assert.isTrue(tweetData.underReview, "Tweet should be under review at the threshold");
+      const review = (await getEvents(provider, program, signature)).find(e => e.name === "tweetUnderReview");
+      assert.isDefined(review, "TweetUnderReview event should be emitted");
+    });
+
+    it("Should let a moderator dismiss a review without hiding the tweet", async () => {
+      const [config_pkey, config_bump] = getConfigAddress(program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey);
+
+      const signature = await program.methods.dismissReview().accounts(
+        {
+          moderator: charlie.publicKey,
+          config: config_pkey,
+          tweet: tweet_pkey,
+        }
+      ).signers([charlie]).rpc({ commitment: "confirmed" })
+
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.isFalse(tweetData.underReview, "Tweet should no longer be under review");
+      assert.isNull(tweetData.hiddenReason, "Dismissing a review should not hide the tweet");
+      const dismissed = (await getEvents(provider, program, signature)).find(e => e.name === "reviewDismissed");
+      assert.isDefined(dismissed, "ReviewDismissed event should be emitted");
+    });
+
+    it("Should fail when dismissing a review that is not pending", async () => {
+      const [config_pkey, config_bump] = getConfigAddress(program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.dismissReview().accounts(
+          {
+            moderator: charlie.publicKey,
+            config: config_pkey,
+            tweet: tweet_pkey,
+          }
+        ).signers([charlie]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "NotUnderReview", "Expected 'NotUnderReview' error once the review was dismissed");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to dismiss a review twice");
+    });
+  });
+
+  describe("Rate Limit", async () => {
+    const topic_rate_limit = "Rate limited";
+
+    async function createTweet(author: anchor.web3.Keypair, content: string) {
+      const [author_state_pkey, author_state_bump] = getAuthorStateAddress(author.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, author.publicKey);
+
+      await program.methods.createTweet(topic_rate_limit, content).accounts(
+        {
+          tweetAuthority: author.publicKey,
+          authorState: author_state_pkey,
+          config: getConfigAddress(program.programId)[0],
+          tweet: tweet_pkey,
+          topicIndex: getTopicIndexAddress(topic_rate_limit, program.programId)[0],
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([author]).rpc({ commitment: "confirmed" })
+    }
+
+    it("Should fail when the rate limit allows no actions", async () => {
+      let should_fail = "This should fail";
+      try {
+        await program.methods.setRateLimit(new anchor.BN(10_000), new anchor.BN(0)).accounts(
+          {
+            admin: provider.wallet.publicKey,
+            config: getConfigAddress(program.programId)[0],
+          }
+        ).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "InvalidRateLimit", "Expected 'InvalidRateLimit' error for a window with zero actions");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to set a rate limit that blocks every action");
+    });
+
+    it("Should reject actions beyond the per-window limit", async () => {
+      await airdrop(provider.connection, dave.publicKey);
+
+      const signature = await program.methods.setRateLimit(new anchor.BN(10_000), new anchor.BN(2)).accounts(
+        {
+          admin: provider.wallet.publicKey,
+          config: getConfigAddress(program.programId)[0],
+        }
+      ).rpc({ commitment: "confirmed" })
+
+      const updated = (await getEvents(provider, program, signature)).find(e => e.name === "configUpdated");
+      assert.isDefined(updated, "ConfigUpdated event should be emitted");
+      assert.strictEqual(updated.data.rateLimitMaxActions.toNumber(), 2, "ConfigUpdated should carry the new rate limit");
+
+      await createTweet(dave, "First");
+      await createTweet(dave, "Second");
+
+      let should_fail = "This should fail";
+      try {
+        await createTweet(dave, "Third");
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "RateLimited", "Expected 'RateLimited' error past the limit");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to post more than the limit per window");
+
+      let authorStateData = await program.account.authorState.fetch(getAuthorStateAddress(dave.publicKey, program.programId)[0]);
+      assert.strictEqual(authorStateData.actionsInWindow.toString(), "2", "Only successful actions should be counted");
+    });
+
+    it("Should allow actions again once rate limiting is disabled", async () => {
+      await program.methods.setRateLimit(new anchor.BN(0), new anchor.BN(0)).accounts(
+        {
+          admin: provider.wallet.publicKey,
+          config: getConfigAddress(program.programId)[0],
+        }
+      ).rpc({ commitment: "confirmed" })
+
+      await createTweet(dave, "Third");
+
+      let authorStateData = await program.account.authorState.fetch(getAuthorStateAddress(dave.publicKey, program.programId)[0]);
+      assert.strictEqual(authorStateData.tweetCount.toString(), "3", "Dave should have three tweets");
+    });
+  });
+
   describe("Edge Cases and Error Handling", async () => {
     it("Should allow tweet author to react to their own tweet", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey);
       const [reaction_pkey, reaction_bump] = getReactionAddress(bob.publicKey, tweet_pkey, program.programId);
 
       await program.methods.dislikeTweet().accounts(
@@ -788,6 +3160,7 @@ describe("twitter", () => {
           reactionAuthor: bob.publicKey,
           tweetReaction: reaction_pkey,
           tweet: tweet_pkey,
+          authorBlock: await getAuthorBlockAddress(program, tweet_pkey, bob.publicKey),
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([bob]).rpc({ commitment: "confirmed" })
@@ -799,15 +3172,18 @@ describe("twitter", () => {
     });
 
     it("Should allow tweet author to comment on their own tweet", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey);
       const bob_comment = "Thanks for the likes everyone!";
-      const [comment_pkey, comment_bump] = getCommentAddress(bob_comment, bob.publicKey, tweet_pkey, program.programId);
+      const [comment_pkey, comment_bump] = await getNextCommentAddress(program, tweet_pkey);
 
       await program.methods.commentTweet(bob_comment).accounts(
         {
           commentAuthor: bob.publicKey,
+          authorState: getAuthorStateAddress(bob.publicKey, program.programId)[0],
+          config: getConfigAddress(program.programId)[0],
           comment: comment_pkey,
-          tweet: tweet_pkey,
+          parentTweet: tweet_pkey,
+          authorBlock: await getAuthorBlockAddress(program, tweet_pkey, bob.publicKey),
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([bob]).rpc({ commitment: "confirmed" })
@@ -819,15 +3195,15 @@ describe("twitter", () => {
 
     it("Should maintain correct final state across all tweets and reactions", async () => {
       // Bob's first tweet should have: 2 likes, 1 dislike
-      const [tweet1_pkey, tweet1_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet1_pkey, tweet1_bump] = getTweetAddress(topic_bob1, bob.publicKey);
       await checkTweet(program, tweet1_pkey, bob.publicKey, topic_bob1, content_bob1, 2, 1, tweet1_bump);
 
       // Bob's second tweet should have: 1 like, 0 dislikes
-      const [tweet2_pkey, tweet2_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
+      const [tweet2_pkey, tweet2_bump] = getTweetAddress(topic_bob4, bob.publicKey);
       await checkTweet(program, tweet2_pkey, bob.publicKey, topic_bob4, content_bob4, 1, 0, tweet2_bump);
 
       // Charlie's tweet should have: 0 likes, 0 dislikes
-      const [tweet3_pkey, tweet3_bump] = getTweetAddress(topic_bob1, charlie.publicKey, program.programId);
+      const [tweet3_pkey, tweet3_bump] = getTweetAddress(topic_bob1, charlie.publicKey);
       await checkTweet(program, tweet3_pkey, charlie.publicKey, topic_bob1, "Charlie's version", 0, 0, tweet3_bump);
     });
   });
@@ -839,21 +3215,35 @@ async function airdrop(connection: any, address: any, amount = 1000000000) {
   await connection.confirmTransaction(await connection.requestAirdrop(address, amount), "confirmed");
 }
 
-function getCommentAddress(comment_content: string, author: PublicKey, parent_tweet: PublicKey, programID: PublicKey) {
-  let hexString = crypto.createHash('sha256').update(comment_content, 'utf-8').digest('hex');
-  let content_seed = Uint8Array.from(Buffer.from(hexString, 'hex'));
-
-
+function getCommentAddress(parent_tweet: PublicKey, comment_id: number, programID: PublicKey) {
   return PublicKey.findProgramAddressSync(
     [
       anchor.utils.bytes.utf8.encode(COMMENT_SEED),
-      author.toBuffer(),
-      content_seed,
       parent_tweet.toBuffer(),
+      new anchor.BN(comment_id).toArrayLike(Buffer, "le", 8),
     ], programID);
 }
 
-function getTweetAddress(topic: string, author: PublicKey, programID: PublicKey) {
+async function getNextCommentAddress(program: anchor.Program<Twitter>, parent_tweet: PublicKey) {
+  const tweetData = await program.account.tweet.fetch(parent_tweet);
+  return getCommentAddress(parent_tweet, tweetData.nextCommentId.toNumber(), program.programId);
+}
+
+// Tweets are addressed by their author's sequence number, the tests refer to
+// them by topic and author, so the address of every created tweet is kept here.
+const tweetAddresses = new Map<string, [PublicKey, number]>();
+
+function registerTweetAddress(topic: string, author: PublicKey, address: [PublicKey, number]) {
+  tweetAddresses.set(`${author.toBase58()}/${topic}`, address);
+}
+
+function getTweetAddress(topic: string, author: PublicKey): [PublicKey, number] {
+  const address = tweetAddresses.get(`${author.toBase58()}/${topic}`);
+  assert.isDefined(address, `No tweet was created for topic '${topic}'`);
+  return address;
+}
+
+function getLegacyTweetAddress(topic: string, author: PublicKey, programID: PublicKey) {
   return PublicKey.findProgramAddressSync(
     [
       anchor.utils.bytes.utf8.encode(topic),
@@ -862,6 +3252,137 @@ function getTweetAddress(topic: string, author: PublicKey, programID: PublicKey)
     ], programID);
 }
 
+function getProfileAddress(authority: PublicKey, programID: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode(USER_PROFILE_SEED),
+      authority.toBuffer(),
+    ], programID);
+}
+
+function getHandleAddress(handle: string, programID: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode(HANDLE_SEED),
+      anchor.utils.bytes.utf8.encode(handle),
+    ], programID);
+}
+
+function getFollowAddress(follower: PublicKey, followee: PublicKey, programID: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode(FOLLOW_SEED),
+      follower.toBuffer(),
+      followee.toBuffer(),
+    ], programID);
+}
+
+function getRetweetAddress(retweeter: PublicKey, tweet: PublicKey, programID: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode(RETWEET_SEED),
+      retweeter.toBuffer(),
+      tweet.toBuffer(),
+    ], programID);
+}
+
+function getBookmarkAddress(user: PublicKey, tweet: PublicKey, programID: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode(BOOKMARK_SEED),
+      user.toBuffer(),
+      tweet.toBuffer(),
+    ], programID);
+}
+
+function getPollAddress(tweet: PublicKey, programID: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode(POLL_SEED),
+      tweet.toBuffer(),
+    ], programID);
+}
+
+function getPollVoteAddress(poll: PublicKey, voter: PublicKey, programID: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode(POLL_VOTE_SEED),
+      poll.toBuffer(),
+      voter.toBuffer(),
+    ], programID);
+}
+
+function getConfigAddress(programID: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode(CONFIG_SEED),
+    ], programID);
+}
+
+function getReportAddress(tweet: PublicKey, reporter: PublicKey, programID: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode(REPORT_SEED),
+      tweet.toBuffer(),
+      reporter.toBuffer(),
+    ], programID);
+}
+
+function getProgramDataAddress(programID: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [programID.toBuffer()],
+    BPF_LOADER_UPGRADEABLE_PROGRAM_ID
+  )[0];
+}
+
+function getBlockAddress(blocker: PublicKey, blocked: PublicKey, programID: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode(BLOCK_SEED),
+      blocker.toBuffer(),
+      blocked.toBuffer(),
+    ], programID);
+}
+
+// Block PDA a reaction or comment must prove absent: the tweet author blocking `actor`.
+async function getAuthorBlockAddress(program: anchor.Program<Twitter>, tweet: PublicKey, actor: PublicKey) {
+  const tweetData = await program.account.tweet.fetchNullable(tweet);
+  const author = tweetData ? tweetData.tweetAuthor : PublicKey.default;
+  return getBlockAddress(author, actor, program.programId)[0];
+}
+
+function getAuthorStateAddress(author: PublicKey, programID: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode(AUTHOR_STATE_SEED),
+      author.toBuffer(),
+    ], programID);
+}
+
+function getSequencedTweetAddress(author: PublicKey, tweet_id: number, programID: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode(TWEET_SEED),
+      author.toBuffer(),
+      new anchor.BN(tweet_id).toArrayLike(Buffer, "le", 8),
+    ], programID);
+}
+
+async function getNextTweetAddress(program: anchor.Program<Twitter>, author: PublicKey) {
+  const [author_state] = getAuthorStateAddress(author, program.programId);
+  const authorState = await program.account.authorState.fetchNullable(author_state);
+  const tweet_id = authorState ? authorState.tweetCount.toNumber() : 0;
+  return getSequencedTweetAddress(author, tweet_id, program.programId);
+}
+
+function getTopicIndexAddress(topic: string, programID: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode(TOPIC_INDEX_SEED),
+      crypto.createHash("sha256").update(topic).digest(),
+    ], programID);
+}
+
 function getReactionAddress(author: PublicKey, tweet: PublicKey, programID: PublicKey) {
   return PublicKey.findProgramAddressSync(
     [
@@ -871,6 +3392,83 @@ function getReactionAddress(author: PublicKey, tweet: PublicKey, programID: Publ
     ], programID);
 }
 
+async function getEvents(provider: anchor.AnchorProvider, program: anchor.Program<Twitter>, signature: string) {
+  const tx = await provider.connection.getTransaction(signature, { commitment: "confirmed", maxSupportedTransactionVersion: 0 });
+  const parser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+  return Array.from(parser.parseLogs(tx.meta.logMessages));
+}
+
+function getTipLedgerAddress(tweet: PublicKey, mint: PublicKey, programID: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode(TIP_LEDGER_SEED),
+      tweet.toBuffer(),
+      mint.toBuffer(),
+    ], programID);
+}
+
+function getAssociatedTokenAddress(mint: PublicKey, owner: PublicKey, token_program: PublicKey = TOKEN_PROGRAM_ID) {
+  return PublicKey.findProgramAddressSync(
+    [owner.toBuffer(), token_program.toBuffer(), mint.toBuffer()],
+    ASSOCIATED_TOKEN_PROGRAM_ID
+  )[0];
+}
+
+// Creates a 6-decimal mint owned by `owner` and mints `amount` into the owner's ATA,
+// using raw token program instructions to avoid an extra client dependency.
+// Works with both the SPL Token and the Token-2022 program.
+async function createMintWithBalance(provider: anchor.AnchorProvider, mint: anchor.web3.Keypair, owner: anchor.web3.Keypair, amount: number, token_program: PublicKey = TOKEN_PROGRAM_ID) {
+  const MINT_SIZE = 82;
+  const lamports = await provider.connection.getMinimumBalanceForRentExemption(MINT_SIZE);
+  const owner_ata = getAssociatedTokenAddress(mint.publicKey, owner.publicKey, token_program);
+
+  const initializeMint2 = Buffer.alloc(67);
+  initializeMint2.writeUInt8(20, 0);
+  initializeMint2.writeUInt8(6, 1);
+  owner.publicKey.toBuffer().copy(initializeMint2, 2);
+
+  const mintTo = Buffer.alloc(9);
+  mintTo.writeUInt8(7, 0);
+  mintTo.writeBigUInt64LE(BigInt(amount), 1);
+
+  const tx = new anchor.web3.Transaction().add(
+    anchor.web3.SystemProgram.createAccount({
+      fromPubkey: owner.publicKey,
+      newAccountPubkey: mint.publicKey,
+      space: MINT_SIZE,
+      lamports,
+      programId: token_program,
+    }),
+    new anchor.web3.TransactionInstruction({
+      programId: token_program,
+      keys: [{ pubkey: mint.publicKey, isSigner: false, isWritable: true }],
+      data: initializeMint2,
+    }),
+    new anchor.web3.TransactionInstruction({
+      programId: ASSOCIATED_TOKEN_PROGRAM_ID,
+      keys: [
+        { pubkey: owner.publicKey, isSigner: true, isWritable: true },
+        { pubkey: owner_ata, isSigner: false, isWritable: true },
+        { pubkey: owner.publicKey, isSigner: false, isWritable: false },
+        { pubkey: mint.publicKey, isSigner: false, isWritable: false },
+        { pubkey: anchor.web3.SystemProgram.programId, isSigner: false, isWritable: false },
+        { pubkey: token_program, isSigner: false, isWritable: false },
+      ],
+      data: Buffer.from([1]),
+    }),
+    new anchor.web3.TransactionInstruction({
+      programId: token_program,
+      keys: [
+        { pubkey: mint.publicKey, isSigner: false, isWritable: true },
+        { pubkey: owner_ata, isSigner: false, isWritable: true },
+        { pubkey: owner.publicKey, isSigner: true, isWritable: false },
+      ],
+      data: mintTo,
+    }),
+  );
+  await anchor.web3.sendAndConfirmTransaction(provider.connection, tx, [owner, mint], { commitment: "confirmed" });
+}
+
 class SolanaError {
   static contains(logs, error): boolean {
     const match = logs?.filter(s => s.includes(error));
@@ -890,6 +3488,7 @@ async function checkTweet(
   bump?: number,
 ) {
   let tweetData = await program.account.tweet.fetch(tweet);
+  assert.isTrue(tweetData.createdSlot.gtn(0), "Tweet creation slot should be recorded");
 
   if (tweet_author) {
     assert.strictEqual(tweetData.tweetAuthor.toString(), tweet_author.toString(), `Tweet author should be ${tweet_author.toString()} but was ${tweetData.tweetAuthor.toString()}`)
@@ -901,10 +3500,12 @@ async function checkTweet(
     assert.strictEqual(tweetData.content, content, `Tweet content should be "${content}" but was "${tweetData.content}"`);
   }
   if (likes || likes == 0) {
-    assert.strictEqual(tweetData.likes.toString(), new anchor.BN(likes).toString(), `Tweet likes should be ${likes} but was ${tweetData.likes.toString()}`)
+    const tweetLikes = tweetData.reactions[REACTION_LIKE];
+    assert.strictEqual(tweetLikes.toString(), new anchor.BN(likes).toString(), `Tweet likes should be ${likes} but was ${tweetLikes.toString()}`)
   }
   if (dislikes || dislikes == 0) {
-    assert.strictEqual(tweetData.dislikes.toString(), new anchor.BN(dislikes).toString(), `Tweet dislikes should be ${dislikes} but was ${tweetData.dislikes.toString()}`)
+    const tweetDislikes = tweetData.reactions[REACTION_DISLIKE];
+    assert.strictEqual(tweetDislikes.toString(), new anchor.BN(dislikes).toString(), `Tweet dislikes should be ${dislikes} but was ${tweetDislikes.toString()}`)
   }
   if (bump) {
     assert.strictEqual(tweetData.bump.toString(), bump.toString(), `Tweet bump should be ${bump} but was ${tweetData.bump}`)
@@ -919,6 +3520,7 @@ async function checkReaction(
   bump?: number,
 ) {
   let reactionData = await program.account.reaction.fetch(reaction);
+  assert.isTrue(reactionData.createdSlot.gtn(0), "Reaction creation slot should be recorded");
 
   if (reaction_author) {
     assert.strictEqual(reactionData.reactionAuthor.toString(), reaction_author.toString(), `Reaction author should be ${reaction_author.toString()} but was ${reactionData.reactionAuthor.toString()}`)
@@ -941,6 +3543,7 @@ async function checkComment(
   bump?: number,
 ) {
   let commentnData = await program.account.comment.fetch(comment);
+  assert.isTrue(commentnData.createdSlot.gtn(0), "Comment creation slot should be recorded");
 
   if (comment_author) {
     assert.strictEqual(commentnData.commentAuthor.toString(), comment_author.toString(), `Comment author should be ${comment_author.toString()} but was ${commentnData.commentAuthor.toString()}`)